@@ -0,0 +1,406 @@
+use ark_ff::PrimeField;
+use fft::domain::EvaluationDomain;
+use fft::reed_solomon::{DecodeError, ReedSolomon};
+use merkle::{Digest, MerkleTree};
+use sha3::{Digest as _, Keccak256};
+
+/// A `k×k` data matrix Reed–Solomon extended to `2k×2k`, with a Keccak256
+/// [`MerkleTree`] root committing to every row and every column, one cell
+/// per leaf.
+///
+/// The original data occupies the top-left quadrant. Each row and column of
+/// the square is the degree `< k` polynomial through its first `k` entries,
+/// which are its values on the order-`k` subgroup `H`, followed by its values
+/// on the coset `ω·H`, where `ω` has order `2k`.
+#[derive(Debug, Clone)]
+pub struct ExtendedDataSquare<F: PrimeField> {
+    k: usize,
+    cells: Vec<F>,
+    row_roots: Vec<Digest>,
+    column_roots: Vec<Digest>,
+}
+
+/// One cell of the square with its Merkle path in the row tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample<F: PrimeField> {
+    pub row: usize,
+    pub column: usize,
+    pub value: F,
+    pub proof: Vec<Digest>,
+}
+
+impl<F: PrimeField> ExtendedDataSquare<F> {
+    /// Extends `data`, a row-major `k×k` matrix, to the `2k×2k` square.
+    pub fn extend(data: &[F], k: usize) -> Self {
+        assert!(
+            k > 0 && k.is_power_of_two(),
+            "k must be a power of 2 and non-zero"
+        );
+        assert_eq!(data.len(), k * k, "data must hold k·k elements");
+
+        let width = 2 * k;
+        let mut cells = vec![F::zero(); width * width];
+        for (row, chunk) in data.chunks(k).enumerate() {
+            cells[row * width..(row + 1) * width].copy_from_slice(&extend_line(chunk));
+        }
+        for column in 0..width {
+            let original: Vec<F> = (0..k).map(|row| cells[row * width + column]).collect();
+            for (row, value) in extend_line(&original).into_iter().enumerate().skip(k) {
+                cells[row * width + column] = value;
+            }
+        }
+
+        Self::from_cells(k, cells)
+    }
+
+    /// Rebuilds the square from a partial one, `None` marking a missing
+    /// cell, by repeatedly decoding every row and column that has at least
+    /// `k` known cells.
+    ///
+    /// Returns `None` if the known cells do not determine the square, or if
+    /// some row or column holds more than `k` cells that are not on one
+    /// codeword. Lines that arrive complete, or are completed by crossing
+    /// lines, are checked against the code once the square is filled.
+    pub fn reconstruct(k: usize, cells: &[Option<F>]) -> Option<Self> {
+        let width = 2 * k;
+        assert_eq!(cells.len(), width * width, "expected 2k·2k cells");
+
+        let mut cells = cells.to_vec();
+        let code = ReedSolomon::<F>::new(k, width);
+        loop {
+            let mut progress = false;
+            for line in 0..2 * width {
+                let positions: Vec<usize> = if line < width {
+                    (0..width).map(|column| line * width + column).collect()
+                } else {
+                    (0..width).map(|row| row * width + line - width).collect()
+                };
+
+                let known = positions.iter().filter(|&&i| cells[i].is_some()).count();
+                if known == width || known < k {
+                    continue;
+                }
+
+                let values: Vec<Option<F>> = positions.iter().map(|&i| cells[i]).collect();
+                for (&i, value) in positions.iter().zip(decode_line(&code, &values).ok()?) {
+                    cells[i] = Some(value);
+                }
+                progress = true;
+            }
+
+            if cells.iter().all(Option::is_some) {
+                break;
+            }
+            if !progress {
+                return None;
+            }
+        }
+
+        let square = Self::from_cells(k, cells.into_iter().map(Option::unwrap).collect());
+        (0..width)
+            .all(|i| is_codeword(&square.row(i)) && is_codeword(&square.column(i)))
+            .then_some(square)
+    }
+
+    fn from_cells(k: usize, cells: Vec<F>) -> Self {
+        let width = 2 * k;
+        let mut square = Self {
+            k,
+            cells,
+            row_roots: vec![],
+            column_roots: vec![],
+        };
+        square.row_roots = (0..width)
+            .map(|i| line_tree(&square.row(i)).root())
+            .collect();
+        square.column_roots = (0..width)
+            .map(|i| line_tree(&square.column(i)).root())
+            .collect();
+        square
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    pub fn width(&self) -> usize {
+        2 * self.k
+    }
+
+    pub fn cell(&self, row: usize, column: usize) -> F {
+        self.cells[row * self.width() + column]
+    }
+
+    pub fn row(&self, row: usize) -> Vec<F> {
+        self.cells[row * self.width()..(row + 1) * self.width()].to_vec()
+    }
+
+    pub fn column(&self, column: usize) -> Vec<F> {
+        (0..self.width())
+            .map(|row| self.cell(row, column))
+            .collect()
+    }
+
+    /// The original `k×k` data, row-major.
+    pub fn original_data(&self) -> Vec<F> {
+        (0..self.k)
+            .flat_map(|row| (0..self.k).map(move |column| (row, column)))
+            .map(|(row, column)| self.cell(row, column))
+            .collect()
+    }
+
+    pub fn row_roots(&self) -> &[Digest] {
+        &self.row_roots
+    }
+
+    pub fn column_roots(&self) -> &[Digest] {
+        &self.column_roots
+    }
+
+    /// Single commitment to the square: the hash of all row roots followed
+    /// by all column roots.
+    pub fn data_root(&self) -> Digest {
+        data_root(&self.row_roots, &self.column_roots)
+    }
+
+    /// Opens one cell against its row root.
+    pub fn sample(&self, row: usize, column: usize) -> Sample<F> {
+        Sample {
+            row,
+            column,
+            value: self.cell(row, column),
+            proof: line_tree(&self.row(row)).open(column),
+        }
+    }
+}
+
+/// Checks a sample against the row roots a light client downloaded.
+pub fn verify_sample<F: PrimeField>(row_roots: &[Digest], sample: &Sample<F>) -> bool {
+    let Some(root) = row_roots.get(sample.row) else {
+        return false;
+    };
+    let width = row_roots.len();
+    if !width.is_power_of_two()
+        || sample.column >= width
+        || sample.proof.len() != width.trailing_zeros() as usize
+    {
+        return false;
+    }
+    merkle::verify_path::<Keccak256, F>(root, sample.column, &[sample.value], &sample.proof)
+}
+
+/// Hash of all row roots followed by all column roots.
+pub fn data_root(row_roots: &[Digest], column_roots: &[Digest]) -> Digest {
+    let mut hasher = Keccak256::new();
+    for root in row_roots.iter().chain(column_roots.iter()) {
+        hasher.update(root);
+    }
+    hasher.finalize().to_vec()
+}
+
+/// Probability that `samples` uniformly random cells all succeed on a square
+/// that cannot be reconstructed. Withholding needs at least `(k+1)²` of the
+/// `4k²` cells, so each sample misses them with probability at most
+/// `1 - (k+1)²/4k²`.
+pub fn unavailability_probability(k: usize, samples: u32) -> f64 {
+    let withheld = ((k + 1) * (k + 1)) as f64 / (4 * k * k) as f64;
+    (1.0 - withheld).powf(samples as f64)
+}
+
+/// Extends `k` values on `H` to the `2k` values on `H` then `ω·H`.
+fn extend_line<F: PrimeField>(values: &[F]) -> Vec<F> {
+    let k = values.len();
+    let coefficients = EvaluationDomain::new(k).ifft(values);
+    let evaluations = ReedSolomon::new(k, 2 * k).encode(&coefficients);
+
+    // the natural order of the 2k domain interleaves H (even) and ω·H (odd)
+    let mut line = vec![F::zero(); 2 * k];
+    for (i, value) in evaluations.into_iter().enumerate() {
+        line[(i % 2) * k + i / 2] = value;
+    }
+    line
+}
+
+/// Whether a line is the extension of its first `k` values.
+fn is_codeword<F: PrimeField>(line: &[F]) -> bool {
+    extend_line(&line[..line.len() / 2]) == line
+}
+
+/// Fills in the missing values of a line from at least `k` known ones.
+fn decode_line<F: PrimeField>(
+    code: &ReedSolomon<F>,
+    line: &[Option<F>],
+) -> Result<Vec<F>, DecodeError> {
+    let k = code.data_len();
+    let natural: Vec<Option<F>> = (0..2 * k).map(|i| line[(i % 2) * k + i / 2]).collect();
+    let evaluations = code.encode(&code.decode(&natural)?);
+
+    let mut decoded = vec![F::zero(); 2 * k];
+    for (i, value) in evaluations.into_iter().enumerate() {
+        decoded[(i % 2) * k + i / 2] = value;
+    }
+    Ok(decoded)
+}
+
+/// Keccak256 tree with one cell per leaf.
+fn line_tree<F: PrimeField>(values: &[F]) -> MerkleTree<Keccak256> {
+    MerkleTree::new(&values.iter().map(|value| vec![*value]).collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_377::Fr;
+    use ark_ff::UniformRand;
+
+    fn random_values(n: usize) -> Vec<Fr> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_extend_keeps_original_data() {
+        let data = random_values(16);
+        let square = ExtendedDataSquare::extend(&data, 4);
+
+        assert_eq!(square.width(), 8);
+        assert_eq!(square.original_data(), data);
+        assert_eq!(square.cell(1, 2), data[4 + 2]);
+    }
+
+    #[test]
+    fn test_rows_and_columns_are_codewords() {
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        for i in 0..square.width() {
+            assert!(is_codeword(&square.row(i)), "row {}", i);
+            assert!(is_codeword(&square.column(i)), "column {}", i);
+        }
+    }
+
+    #[test]
+    fn test_extend_line_is_evaluation_on_coset() {
+        let values = random_values(4);
+        let line = extend_line(&values);
+        let coefficients = EvaluationDomain::new(4).ifft(&values);
+        let coset =
+            EvaluationDomain::<Fr>::new(4).get_coset(EvaluationDomain::<Fr>::new(8).generator());
+        assert_eq!(line[4..], coset.fft(&coefficients)[..]);
+    }
+
+    #[test]
+    fn test_samples_verify() {
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        for (row, column) in [(0, 0), (3, 7), (7, 2), (5, 5)] {
+            let sample = square.sample(row, column);
+            assert!(verify_sample(square.row_roots(), &sample));
+        }
+    }
+
+    #[test]
+    fn test_tampered_sample_fails() {
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        let mut sample = square.sample(2, 6);
+        sample.value += Fr::from(1);
+        assert!(!verify_sample(square.row_roots(), &sample));
+
+        let mut sample = square.sample(2, 6);
+        sample.row = 3;
+        assert!(!verify_sample(square.row_roots(), &sample));
+
+        let mut sample = square.sample(2, 6);
+        sample.proof.pop();
+        assert!(!verify_sample(square.row_roots(), &sample));
+    }
+
+    #[test]
+    fn test_oversized_proof_fails() {
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        let mut sample = square.sample(2, 6);
+        sample.proof = vec![sample.proof[0].clone(); 64];
+        assert!(!verify_sample(square.row_roots(), &sample));
+        sample.proof.push(sample.proof[0].clone());
+        assert!(!verify_sample(square.row_roots(), &sample));
+
+        let roots = &square.row_roots()[..6];
+        assert!(!verify_sample(roots, &square.sample(2, 1)));
+    }
+
+    #[test]
+    fn test_reconstruct_from_one_quadrant() {
+        let data = random_values(16);
+        let square = ExtendedDataSquare::extend(&data, 4);
+        let partial: Vec<Option<Fr>> = (0..64)
+            .map(|i| (i / 8 >= 4 && i % 8 >= 4).then_some(square.cells[i]))
+            .collect();
+
+        let rebuilt = ExtendedDataSquare::reconstruct(4, &partial).unwrap();
+        assert_eq!(rebuilt.original_data(), data);
+        assert_eq!(rebuilt.data_root(), square.data_root());
+    }
+
+    #[test]
+    fn test_reconstruct_from_original_quadrant() {
+        // rows 0..k decode first, then every column has k known cells
+        let data = random_values(16);
+        let square = ExtendedDataSquare::extend(&data, 4);
+        let partial: Vec<Option<Fr>> = (0..64)
+            .map(|i| (i / 8 < 4 && i % 8 < 4).then_some(square.cells[i]))
+            .collect();
+
+        let rebuilt = ExtendedDataSquare::reconstruct(4, &partial).unwrap();
+        assert_eq!(rebuilt.cells, square.cells);
+        assert_eq!(rebuilt.column_roots(), square.column_roots());
+    }
+
+    #[test]
+    fn test_reconstruct_fails_when_withheld() {
+        // withholding a (k+1)×(k+1) block leaves k-1 known cells in its lines
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        let partial: Vec<Option<Fr>> = (0..64)
+            .map(|i| (i / 8 > 4 || i % 8 > 4).then_some(square.cells[i]))
+            .collect();
+        assert!(ExtendedDataSquare::reconstruct(4, &partial).is_none());
+    }
+
+    #[test]
+    fn test_reconstruct_rejects_inconsistent_cells() {
+        // row 0 keeps five cells, one of them corrupted
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        let mut partial: Vec<Option<Fr>> = (0..64)
+            .map(|i| (i / 8 < 4 && i % 8 < 4).then_some(square.cells[i]))
+            .collect();
+        partial[4] = Some(square.cells[4] + Fr::from(1));
+        assert!(ExtendedDataSquare::reconstruct(4, &partial).is_none());
+    }
+
+    #[test]
+    fn test_reconstruct_rejects_tampered_complete_square() {
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        let mut partial: Vec<Option<Fr>> = square.cells.iter().copied().map(Some).collect();
+        assert!(ExtendedDataSquare::reconstruct(4, &partial).is_some());
+
+        partial[19] = Some(square.cells[19] + Fr::from(1));
+        assert!(ExtendedDataSquare::reconstruct(4, &partial).is_none());
+    }
+
+    #[test]
+    fn test_reconstruct_rejects_tampered_cell_completed_by_crossing_lines() {
+        // rows 0..k arrive complete, one of them corrupted, and fill every column
+        let square = ExtendedDataSquare::extend(&random_values(16), 4);
+        let mut partial: Vec<Option<Fr>> = (0..64)
+            .map(|i| (i / 8 < 4).then_some(square.cells[i]))
+            .collect();
+        partial[10] = Some(square.cells[10] + Fr::from(1));
+        assert!(ExtendedDataSquare::reconstruct(4, &partial).is_none());
+    }
+
+    #[test]
+    fn test_unavailability_probability() {
+        assert_eq!(unavailability_probability(4, 0), 1.0);
+        let p = unavailability_probability(64, 16);
+        assert!(p > 0.0 && p < 0.01);
+        assert!(unavailability_probability(64, 32) < p);
+        // more samples than fit an i32 must not wrap into a negative power
+        assert_eq!(unavailability_probability(64, u32::MAX), 0.0);
+    }
+}
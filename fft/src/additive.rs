@@ -0,0 +1,249 @@
+use crate::binary_field::BinaryField;
+
+/// Additive NTT of Lin, Chung and Han over a binary field.
+///
+/// The domain is the affine subspace `S = shift + span(β₀, ..., β_(ℓ-1))`
+/// with `βᵢ = 2ⁱ` as a bit vector, so point `j` is `shift + j`. Polynomials
+/// are written in the novel basis `Xⱼ(x) = Π Ŵᵢ(x)^jᵢ`, where `jᵢ` is bit `i`
+/// of `j` and `Ŵᵢ` is the vanishing polynomial of `span(β₀, ..., βᵢ₋₁)`
+/// normalised to `Ŵᵢ(βᵢ) = 1`. Each `Ŵᵢ` is `F₂`-linear, which lets every
+/// layer use one twiddle per block in place of roots of unity.
+#[derive(Debug, Clone)]
+pub struct AdditiveNtt<F: BinaryField> {
+    log_size: u32,
+    shift: F,
+    /// `twiddles[i][j] = Ŵᵢ(point j·2^(i+1))`, the twiddle of block `j` in layer `i`.
+    twiddles: Vec<Vec<F>>,
+}
+
+impl<F: BinaryField> AdditiveNtt<F> {
+    /// Transform over the subspace of the first `2^log_size` field elements.
+    pub fn new(log_size: u32) -> Self {
+        Self::with_shift(log_size, F::ZERO)
+    }
+
+    pub fn with_shift(log_size: u32, shift: F) -> Self {
+        assert!(log_size <= F::BITS, "domain is larger than the field");
+
+        let basis = |i: u32| F::from_bits(1 << i);
+        // values of Wᵢ at βᵢ, ..., β_(ℓ-1) and at the shift, starting from W₀(x) = x
+        let mut at_basis: Vec<F> = (0..log_size).map(basis).collect();
+        let mut at_shift = shift;
+
+        let mut twiddles = Vec::with_capacity(log_size as usize);
+        for i in 0..log_size as usize {
+            let norm_inv = at_basis[0]
+                .inverse()
+                .expect("basis element lies outside the subspace");
+            let normalised: Vec<F> = at_basis.iter().map(|&w| w * norm_inv).collect();
+
+            // Ŵᵢ is affine on the domain: Ŵᵢ(shift + Σ bₘβₘ) = Ŵᵢ(shift) + Σ bₘŴᵢ(βₘ)
+            let mut layer = vec![at_shift * norm_inv];
+            for &w in &normalised[1..] {
+                let shifted: Vec<F> = layer.iter().map(|&t| t + w).collect();
+                layer.extend(shifted);
+            }
+            twiddles.push(layer);
+
+            // Wᵢ₊₁(x) = Wᵢ(x)·(Wᵢ(x) + Wᵢ(βᵢ))
+            let norm = at_basis[0];
+            at_shift *= at_shift + norm;
+            at_basis = at_basis[1..].iter().map(|&w| w * (w + norm)).collect();
+            debug_assert!(i + 1 + at_basis.len() == log_size as usize);
+        }
+
+        Self {
+            log_size,
+            shift,
+            twiddles,
+        }
+    }
+
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    pub fn size(&self) -> usize {
+        1 << self.log_size
+    }
+
+    pub fn shift(&self) -> F {
+        self.shift
+    }
+
+    /// The `j`-th domain point, `shift + j`.
+    pub fn point(&self, j: usize) -> F {
+        self.shift + F::from_bits(j as u128)
+    }
+
+    /// Novel-basis coefficients to evaluations over the domain, in place.
+    pub fn forward(&self, values: &mut [F]) {
+        self.check_len(values);
+        for i in (0..self.log_size as usize).rev() {
+            let half = 1 << i;
+            for (chunk, &twiddle) in values.chunks_mut(2 * half).zip(&self.twiddles[i]) {
+                let (lo, hi) = chunk.split_at_mut(half);
+                for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                    *u += *v * twiddle;
+                    *v += *u;
+                }
+            }
+        }
+    }
+
+    /// Evaluations over the domain to novel-basis coefficients, in place.
+    pub fn inverse(&self, values: &mut [F]) {
+        self.check_len(values);
+        for i in 0..self.log_size as usize {
+            let half = 1 << i;
+            for (chunk, &twiddle) in values.chunks_mut(2 * half).zip(&self.twiddles[i]) {
+                let (lo, hi) = chunk.split_at_mut(half);
+                for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                    *v += *u;
+                    *u += *v * twiddle;
+                }
+            }
+        }
+    }
+
+    fn check_len(&self, values: &[F]) {
+        assert_eq!(
+            values.len(),
+            self.size(),
+            "input length must match the domain size"
+        );
+    }
+}
+
+/// Systematic Reed–Solomon encoding over a binary field: the message is the
+/// evaluations over the first `2^k` field elements, the codeword the
+/// evaluations of the same polynomial over the first `2^(k + log_blowup)`.
+pub fn reed_solomon_encode<F: BinaryField>(message: &[F], log_blowup: u32) -> Vec<F> {
+    assert!(
+        message.len().is_power_of_two(),
+        "message length must be a power of 2"
+    );
+    let log_len = message.len().trailing_zeros();
+
+    let mut coefficients = message.to_vec();
+    AdditiveNtt::<F>::new(log_len).inverse(&mut coefficients);
+
+    // novel-basis polynomials keep their meaning on the larger subspace
+    let ntt = AdditiveNtt::<F>::new(log_len + log_blowup);
+    coefficients.resize(ntt.size(), F::ZERO);
+    ntt.forward(&mut coefficients);
+    coefficients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_field::{BinaryField8, BinaryField32, BinaryField128};
+    use ark_std::rand::RngCore;
+
+    fn random_values<F: BinaryField>(n: usize) -> Vec<F> {
+        let mut rng = ark_std::test_rng();
+        let mask = if F::BITS == 128 {
+            u128::MAX
+        } else {
+            (1 << F::BITS) - 1
+        };
+        (0..n)
+            .map(|_| {
+                let bits = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+                F::from_bits(bits & mask)
+            })
+            .collect()
+    }
+
+    /// `Ŵᵢ(x)` straight from its definition as a product over the subspace.
+    fn normalised_vanishing<F: BinaryField>(i: u32, x: F) -> F {
+        let vanishing = |y: F| (0..1u128 << i).fold(F::ONE, |acc, u| acc * (y + F::from_bits(u)));
+        vanishing(x) * vanishing(F::from_bits(1 << i)).inverse().unwrap()
+    }
+
+    fn evaluate_novel_basis<F: BinaryField>(coefficients: &[F], x: F) -> F {
+        let log_len = coefficients.len().trailing_zeros();
+        let factors: Vec<F> = (0..log_len).map(|i| normalised_vanishing(i, x)).collect();
+        coefficients
+            .iter()
+            .enumerate()
+            .map(|(j, &c)| {
+                (0..log_len)
+                    .filter(|&i| j >> i & 1 == 1)
+                    .fold(c, |acc, i| acc * factors[i as usize])
+            })
+            .fold(F::ZERO, |acc, term| acc + term)
+    }
+
+    #[test]
+    fn test_forward_matches_novel_basis_evaluation() {
+        for log_size in 0..=5 {
+            let ntt = AdditiveNtt::<BinaryField32>::new(log_size);
+            let coefficients = random_values::<BinaryField32>(ntt.size());
+            let mut values = coefficients.clone();
+            ntt.forward(&mut values);
+
+            for (j, value) in values.iter().enumerate() {
+                assert_eq!(*value, evaluate_novel_basis(&coefficients, ntt.point(j)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_forward_on_shifted_domain() {
+        let shift = BinaryField32::from_bits(0xabc0_0000);
+        let ntt = AdditiveNtt::with_shift(4, shift);
+        let coefficients = random_values::<BinaryField32>(16);
+        let mut values = coefficients.clone();
+        ntt.forward(&mut values);
+
+        for (j, value) in values.iter().enumerate() {
+            assert_eq!(*value, evaluate_novel_basis(&coefficients, ntt.point(j)));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ntt = AdditiveNtt::<BinaryField128>::new(8);
+        let coefficients = random_values::<BinaryField128>(256);
+        let mut values = coefficients.clone();
+        ntt.forward(&mut values);
+        ntt.inverse(&mut values);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn test_full_field_domain() {
+        // the whole of GF(2⁸) as the evaluation domain
+        let ntt = AdditiveNtt::<BinaryField8>::new(8);
+        let coefficients = random_values::<BinaryField8>(256);
+        let mut values = coefficients.clone();
+        ntt.forward(&mut values);
+        assert_eq!(
+            values[200],
+            evaluate_novel_basis(&coefficients, BinaryField8::from_bits(200))
+        );
+        ntt.inverse(&mut values);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn test_reed_solomon_encode() {
+        let message = random_values::<BinaryField32>(16);
+        let codeword = reed_solomon_encode(&message, 2);
+        assert_eq!(codeword.len(), 64);
+        assert_eq!(&codeword[..16], &message[..]);
+
+        // the codeword is a polynomial with only 16 novel-basis coefficients
+        let mut coefficients = codeword.clone();
+        AdditiveNtt::<BinaryField32>::new(6).inverse(&mut coefficients);
+        assert!(coefficients[16..].iter().all(|c| c.is_zero()));
+    }
+
+    #[test]
+    #[should_panic(expected = "domain is larger than the field")]
+    fn test_domain_larger_than_field() {
+        AdditiveNtt::<BinaryField8>::new(9);
+    }
+}
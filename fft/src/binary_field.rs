@@ -0,0 +1,285 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A field of characteristic 2 whose elements are bit vectors over a fixed
+/// `F₂` basis, so addition is XOR.
+pub trait BinaryField:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+{
+    /// Degree of the field over `F₂`.
+    const BITS: u32;
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Element with the given coordinates; bits at and above `BITS` must be zero.
+    fn from_bits(bits: u128) -> Self;
+
+    fn to_bits(&self) -> u128;
+
+    /// Multiplicative inverse; `None` for zero.
+    fn inverse(&self) -> Option<Self>;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    fn square(&self) -> Self {
+        *self * *self
+    }
+}
+
+/// Level `LEVEL` of the binary tower `T₀ = F₂`, `Tᵢ₊₁ = Tᵢ[Xᵢ]/(Xᵢ² + Xᵢ₋₁·Xᵢ + 1)`
+/// with `X₋₁ = 1`, a field of `2^(2^LEVEL)` elements.
+///
+/// An element of `Tᵢ₊₁` is `a₀ + a₁·Xᵢ` with `a₀` in the low and `a₁` in the
+/// high half of its bits, so every level embeds in the next by zero-extension
+/// and multiplication recurses through the tower with Karatsuba.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BinaryTowerField<const LEVEL: u32>(u128);
+
+pub type BinaryField1 = BinaryTowerField<0>;
+pub type BinaryField8 = BinaryTowerField<3>;
+pub type BinaryField16 = BinaryTowerField<4>;
+pub type BinaryField32 = BinaryTowerField<5>;
+pub type BinaryField64 = BinaryTowerField<6>;
+pub type BinaryField128 = BinaryTowerField<7>;
+
+impl<const LEVEL: u32> BinaryTowerField<LEVEL> {
+    const MASK: u128 = if LEVEL == 7 {
+        u128::MAX
+    } else {
+        (1 << (1 << LEVEL)) - 1
+    };
+
+    pub fn new(bits: u128) -> Self {
+        assert!(LEVEL <= 7, "tower levels above 7 do not fit in 128 bits");
+        assert_eq!(bits & !Self::MASK, 0, "value does not fit in the field");
+        Self(bits)
+    }
+
+    /// The generator `X_(LEVEL-1)` of this level over the previous one.
+    pub fn generator() -> Self {
+        assert!(LEVEL > 0, "F₂ has no generator over a subfield");
+        Self(1 << (1 << (LEVEL - 1)))
+    }
+
+    /// Embeds an element of a lower level of the tower.
+    pub fn embed<const SUB: u32>(element: BinaryTowerField<SUB>) -> Self {
+        assert!(SUB <= LEVEL, "can only embed a subfield");
+        Self(element.0)
+    }
+}
+
+impl<const LEVEL: u32> BinaryField for BinaryTowerField<LEVEL> {
+    const BITS: u32 = 1 << LEVEL;
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1);
+
+    fn from_bits(bits: u128) -> Self {
+        Self::new(bits)
+    }
+
+    fn to_bits(&self) -> u128 {
+        self.0
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.0 == 0 {
+            return None;
+        }
+        Some(Self(tower_inverse(self.0, LEVEL)))
+    }
+}
+
+impl<const LEVEL: u32> Add for BinaryTowerField<LEVEL> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl<const LEVEL: u32> Sub for BinaryTowerField<LEVEL> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl<const LEVEL: u32> Mul for BinaryTowerField<LEVEL> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(tower_mul(self.0, other.0, LEVEL))
+    }
+}
+
+impl<const LEVEL: u32> AddAssign for BinaryTowerField<LEVEL> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const LEVEL: u32> SubAssign for BinaryTowerField<LEVEL> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const LEVEL: u32> MulAssign for BinaryTowerField<LEVEL> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+/// Product in `T_level`: with `a = a₀ + a₁X`, `b = b₀ + b₁X` and
+/// `X² = g·X + 1`, `ab = (a₀b₀ + a₁b₁) + (a₀b₁ + a₁b₀ + a₁b₁·g)·X`.
+fn tower_mul(a: u128, b: u128, level: u32) -> u128 {
+    if level == 0 {
+        return a & b;
+    }
+
+    let half = 1 << (level - 1);
+    let mask = (1u128 << half) - 1;
+    let (a0, a1) = (a & mask, a >> half);
+    let (b0, b1) = (b & mask, b >> half);
+
+    let low = tower_mul(a0, b0, level - 1);
+    let high = tower_mul(a1, b1, level - 1);
+    let cross = tower_mul(a0 ^ a1, b0 ^ b1, level - 1) ^ low ^ high;
+
+    (low ^ high) | ((cross ^ mul_by_generator(high, level - 1)) << half)
+}
+
+/// `a·Xₗ₋₁` in `T_level`, the multiplication by `g` in the level above.
+fn mul_by_generator(a: u128, level: u32) -> u128 {
+    if level == 0 {
+        return a;
+    }
+
+    let half = 1 << (level - 1);
+    let mask = (1u128 << half) - 1;
+    let (a0, a1) = (a & mask, a >> half);
+
+    // (a₀ + a₁X)·X = a₁ + (a₀ + a₁g)·X
+    a1 | ((a0 ^ mul_by_generator(a1, level - 1)) << half)
+}
+
+/// Inverse in `T_level` through the norm to the subfield:
+/// `(a₀ + a₁X)·(a₀ + a₁g + a₁X) = a₀(a₀ + a₁g) + a₁²`.
+fn tower_inverse(a: u128, level: u32) -> u128 {
+    if level == 0 {
+        return a;
+    }
+
+    let half = 1 << (level - 1);
+    let mask = (1u128 << half) - 1;
+    let (a0, a1) = (a & mask, a >> half);
+
+    let conjugate_low = a0 ^ mul_by_generator(a1, level - 1);
+    let norm = tower_mul(a0, conjugate_low, level - 1) ^ tower_mul(a1, a1, level - 1);
+    let norm_inv = tower_inverse(norm, level - 1);
+
+    tower_mul(conjugate_low, norm_inv, level - 1) | (tower_mul(a1, norm_inv, level - 1) << half)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::rand::RngCore;
+
+    fn random<const LEVEL: u32>(rng: &mut impl RngCore) -> BinaryTowerField<LEVEL> {
+        let bits = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+        BinaryTowerField::new(bits & BinaryTowerField::<LEVEL>::MASK)
+    }
+
+    fn check_field_axioms<const LEVEL: u32>() {
+        let mut rng = ark_std::test_rng();
+        for _ in 0..50 {
+            let a = random::<LEVEL>(&mut rng);
+            let b = random::<LEVEL>(&mut rng);
+            let c = random::<LEVEL>(&mut rng);
+
+            assert_eq!(a * b, b * a);
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a * BinaryTowerField::ONE, a);
+            assert_eq!(a + a, BinaryTowerField::ZERO);
+            if !a.is_zero() {
+                assert_eq!(a * a.inverse().unwrap(), BinaryTowerField::ONE);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_axioms() {
+        check_field_axioms::<1>();
+        check_field_axioms::<3>();
+        check_field_axioms::<5>();
+        check_field_axioms::<7>();
+    }
+
+    #[test]
+    fn test_gf4() {
+        // T₁ = F₂[X₀]/(X₀² + X₀ + 1)
+        let x = BinaryTowerField::<1>::generator();
+        assert_eq!(x * x, x + BinaryTowerField::ONE);
+        assert_eq!(x * x * x, BinaryTowerField::ONE);
+    }
+
+    #[test]
+    fn test_defining_relation() {
+        // X₂² = X₁·X₂ + 1 in T₃
+        let x2 = BinaryField8::generator();
+        let x1 = BinaryField8::embed(BinaryTowerField::<2>::generator());
+        assert_eq!(x2 * x2, x1 * x2 + BinaryField8::ONE);
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        let mut rng = ark_std::test_rng();
+        for _ in 0..10 {
+            let a = random::<3>(&mut rng);
+            if a.is_zero() {
+                continue;
+            }
+            let mut power = BinaryField8::ONE;
+            for _ in 0..255 {
+                power *= a;
+            }
+            assert_eq!(power, BinaryField8::ONE);
+        }
+    }
+
+    #[test]
+    fn test_subfield_embedding_is_homomorphic() {
+        let mut rng = ark_std::test_rng();
+        let a = random::<4>(&mut rng);
+        let b = random::<4>(&mut rng);
+        assert_eq!(
+            BinaryField64::embed(a * b),
+            BinaryField64::embed(a) * BinaryField64::embed(b)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit in the field")]
+    fn test_value_out_of_range() {
+        BinaryField8::new(256);
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The Mersenne prime `2³¹ - 1`.
+pub const MODULUS: u32 = (1 << 31) - 1;
+
+/// Element of the Mersenne-31 field, stored reduced in `[0, p)`.
+///
+/// `p - 1 = 2·3²·7·11·31·151·331` has 2-adicity one, so the field has no
+/// useful multiplicative subgroups; FFTs run over the circle group instead,
+/// whose order `p + 1 = 2³¹` is a power of two.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct M31(u32);
+
+impl M31 {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1);
+
+    pub fn new(value: u32) -> Self {
+        Self(reduce(value as u64))
+    }
+
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    pub fn pow(&self, mut exponent: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base = base.square();
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse by Fermat, `a^(p-2)`; `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.pow(MODULUS as u64 - 2))
+    }
+}
+
+/// Inverts every element with one field inversion (Montgomery's trick).
+///
+/// Panics if any element is zero.
+pub fn batch_inverse(values: &[M31]) -> Vec<M31> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = M31::ONE;
+    for value in values {
+        prefix.push(acc);
+        acc *= *value;
+    }
+
+    let mut inv = acc.inverse().expect("cannot invert zero");
+    let mut result = vec![M31::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        result[i] = inv * prefix[i];
+        inv *= values[i];
+    }
+    result
+}
+
+/// Reduces any `x < 2⁶²` modulo `2³¹ - 1` using `2³¹ ≡ 1`.
+fn reduce(x: u64) -> u32 {
+    let folded = (x & MODULUS as u64) + (x >> 31);
+    let folded = (folded & MODULUS as u64) + (folded >> 31);
+    if folded == MODULUS as u64 {
+        0
+    } else {
+        folded as u32
+    }
+}
+
+impl From<u32> for M31 {
+    fn from(value: u32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Display for M31 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Add for M31 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sum = self.0 + other.0;
+        Self(if sum >= MODULUS { sum - MODULUS } else { sum })
+    }
+}
+
+impl Sub for M31 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Neg for M31 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(if self.0 == 0 { 0 } else { MODULUS - self.0 })
+    }
+}
+
+impl Mul for M31 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(reduce(self.0 as u64 * other.0 as u64))
+    }
+}
+
+impl AddAssign for M31 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for M31 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for M31 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduction() {
+        assert_eq!(M31::new(MODULUS), M31::ZERO);
+        assert_eq!(M31::new(u32::MAX).value(), u32::MAX - 2 * MODULUS);
+        assert_eq!(M31::new(MODULUS - 1) + M31::new(2), M31::ONE);
+        assert_eq!(M31::ZERO - M31::ONE, M31::new(MODULUS - 1));
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let minus_one = -M31::ONE;
+        assert_eq!(minus_one * minus_one, M31::ONE);
+        // 2³¹ ≡ 1
+        assert_eq!(M31::new(2).pow(31), M31::ONE);
+        assert_eq!(
+            M31::new(123_456_789) * M31::new(987_654_321),
+            M31::new(((123_456_789u64 * 987_654_321) % MODULUS as u64) as u32)
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        for value in [1, 2, 3, 1 << 30, MODULUS - 1] {
+            let value = M31::new(value);
+            assert_eq!(value * value.inverse().unwrap(), M31::ONE);
+        }
+        assert!(M31::ZERO.inverse().is_none());
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let values: Vec<M31> = (1..20).map(|i| M31::new(i * 7919)).collect();
+        let inverses = batch_inverse(&values);
+        for (value, inverse) in values.iter().zip(inverses) {
+            assert_eq!(*value * inverse, M31::ONE);
+        }
+    }
+}
@@ -0,0 +1,424 @@
+//! FFT over the circle group of the Mersenne-31 field.
+//!
+//! The points `(x, y)` with `x² + y² = 1` form a group of order `p + 1 = 2³¹`
+//! under `(x₀, y₀)·(x₁, y₁) = (x₀x₁ - y₀y₁, x₀y₁ + y₀x₁)`, giving the
+//! power-of-two structure that `M31` itself lacks. A transform splits a
+//! function on a domain first by `y` over conjugate pairs `(x, ±y)`, then
+//! repeatedly by `x` over pairs `±x`, mapping `x ↦ 2x² - 1` between layers.
+
+pub mod m31;
+
+use m31::{M31, batch_inverse};
+use std::ops::{Add, Neg};
+
+/// Base-2 logarithm of the order of the circle group.
+pub const LOG_ORDER: u32 = 31;
+
+/// A point on the unit circle `x² + y² = 1` over `M31`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CirclePoint {
+    pub x: M31,
+    pub y: M31,
+}
+
+impl CirclePoint {
+    /// The group identity `(1, 0)`.
+    pub fn identity() -> Self {
+        Self {
+            x: M31::ONE,
+            y: M31::ZERO,
+        }
+    }
+
+    /// Generator of the whole group, of order `2³¹`.
+    pub fn generator() -> Self {
+        Self {
+            x: M31::new(2),
+            y: M31::new(1_268_011_823),
+        }
+    }
+
+    /// Generator of the subgroup of order `2^log_size`.
+    pub fn subgroup_generator(log_size: u32) -> Self {
+        assert!(log_size <= LOG_ORDER, "circle group has order 2^31");
+        Self::generator().repeated_double(LOG_ORDER - log_size)
+    }
+
+    pub fn is_on_circle(&self) -> bool {
+        self.x.square() + self.y.square() == M31::ONE
+    }
+
+    pub fn double(&self) -> Self {
+        *self + *self
+    }
+
+    pub fn repeated_double(&self, times: u32) -> Self {
+        (0..times).fold(*self, |point, _| point.double())
+    }
+
+    /// The conjugate `(x, -y)`, which is also the group inverse.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Add for CirclePoint {
+    type Output = Self;
+
+    /// The group law, complex multiplication of `x + iy`.
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+}
+
+impl Neg for CirclePoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.conjugate()
+    }
+}
+
+/// `π(x) = 2x² - 1`, the x-coordinate of the doubled point.
+fn double_x(x: M31) -> M31 {
+    let square = x.square();
+    square + square - M31::ONE
+}
+
+/// Canonic coset `Q + G` of size `2^log_size`, where `G` is the subgroup of
+/// that order and `Q` generates the subgroup of twice the order.
+///
+/// Point `i` is `Q + i·g`. The conjugate of point `i` is point `n - 1 - i`,
+/// and the first half of every layer's x-coordinates pairs `x` at `i` with
+/// `-x` at `m - 1 - i`, which is what the butterflies below rely on.
+#[derive(Debug, Clone)]
+pub struct CircleDomain {
+    log_size: u32,
+    points: Vec<CirclePoint>,
+    /// Butterfly x-coordinates of each layer after the first, `n/4` of them
+    /// in the first line layer and halving down to one.
+    line_twiddles: Vec<Vec<M31>>,
+    line_inverse_twiddles: Vec<Vec<M31>>,
+    y_inverse_twiddles: Vec<M31>,
+}
+
+impl CircleDomain {
+    pub fn new(log_size: u32) -> Self {
+        assert!(
+            (1..LOG_ORDER).contains(&log_size),
+            "circle domain size must be between 2 and 2^30"
+        );
+
+        let size = 1usize << log_size;
+        let step = CirclePoint::subgroup_generator(log_size);
+        let mut points = Vec::with_capacity(size);
+        let mut current = CirclePoint::subgroup_generator(log_size + 1);
+        for _ in 0..size {
+            points.push(current);
+            current = current + step;
+        }
+
+        let mut line_twiddles = vec![];
+        let mut xs: Vec<M31> = points[..size / 2].iter().map(|point| point.x).collect();
+        while xs.len() > 1 {
+            let half = xs.len() / 2;
+            line_twiddles.push(xs[..half].to_vec());
+            xs = xs[..half].iter().map(|&x| double_x(x)).collect();
+        }
+        let line_inverse_twiddles = line_twiddles
+            .iter()
+            .map(|layer| batch_inverse(layer))
+            .collect();
+        let y_values: Vec<M31> = points[..size / 2].iter().map(|point| point.y).collect();
+
+        Self {
+            log_size,
+            points,
+            line_twiddles,
+            line_inverse_twiddles,
+            y_inverse_twiddles: batch_inverse(&y_values),
+        }
+    }
+
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    pub fn size(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn at(&self, i: usize) -> CirclePoint {
+        self.points[i]
+    }
+
+    pub fn points(&self) -> &[CirclePoint] {
+        &self.points
+    }
+
+    /// Evaluates a polynomial over the domain, padding its coefficients with
+    /// zeros to the domain size.
+    pub fn fft(&self, coefficients: &[M31]) -> Vec<M31> {
+        let n = self.size();
+        assert!(
+            coefficients.len() <= n,
+            "polynomial has more coefficients than the domain has points"
+        );
+        let mut coefficients = coefficients.to_vec();
+        coefficients.resize(n, M31::ZERO);
+
+        // f(x, y) = f₀(x) + y·f₁(x)
+        let (even, odd) = split_even_odd(&coefficients);
+        let f0 = self.line_fft(&even, 0);
+        let f1 = self.line_fft(&odd, 0);
+
+        let mut evaluations = vec![M31::ZERO; n];
+        for i in 0..n / 2 {
+            let t = self.points[i].y * f1[i];
+            evaluations[i] = f0[i] + t;
+            evaluations[n - 1 - i] = f0[i] - t;
+        }
+        evaluations
+    }
+
+    /// Interpolates evaluations over the domain back to coefficients.
+    pub fn ifft(&self, evaluations: &[M31]) -> Vec<M31> {
+        let n = self.size();
+        assert_eq!(
+            evaluations.len(),
+            n,
+            "expected one evaluation per domain point"
+        );
+
+        let mut f0 = vec![M31::ZERO; n / 2];
+        let mut f1 = vec![M31::ZERO; n / 2];
+        for i in 0..n / 2 {
+            let (a, b) = (evaluations[i], evaluations[n - 1 - i]);
+            f0[i] = a + b;
+            f1[i] = (a - b) * self.y_inverse_twiddles[i];
+        }
+
+        let coefficients = interleave(&self.line_ifft(&f0, 0), &self.line_ifft(&f1, 0));
+        // every layer skipped its division by 2
+        let size_inv = M31::new(n as u32).inverse().unwrap();
+        coefficients.into_iter().map(|c| c * size_inv).collect()
+    }
+
+    /// `g(x) = g₀(π(x)) + x·g₁(π(x))` over the x-coordinates of `layer`.
+    fn line_fft(&self, coefficients: &[M31], layer: usize) -> Vec<M31> {
+        let m = coefficients.len();
+        if m == 1 {
+            return coefficients.to_vec();
+        }
+
+        let (even, odd) = split_even_odd(coefficients);
+        let g0 = self.line_fft(&even, layer + 1);
+        let g1 = self.line_fft(&odd, layer + 1);
+
+        let mut values = vec![M31::ZERO; m];
+        for i in 0..m / 2 {
+            let t = self.line_twiddles[layer][i] * g1[i];
+            values[i] = g0[i] + t;
+            values[m - 1 - i] = g0[i] - t;
+        }
+        values
+    }
+
+    /// Inverse of `line_fft`, without the division by 2 at each layer.
+    fn line_ifft(&self, values: &[M31], layer: usize) -> Vec<M31> {
+        let m = values.len();
+        if m == 1 {
+            return values.to_vec();
+        }
+
+        let mut g0 = vec![M31::ZERO; m / 2];
+        let mut g1 = vec![M31::ZERO; m / 2];
+        for i in 0..m / 2 {
+            let (a, b) = (values[i], values[m - 1 - i]);
+            g0[i] = a + b;
+            g1[i] = (a - b) * self.line_inverse_twiddles[layer][i];
+        }
+
+        interleave(
+            &self.line_ifft(&g0, layer + 1),
+            &self.line_ifft(&g1, layer + 1),
+        )
+    }
+}
+
+/// Polynomial in the circle FFT basis: coefficient `j` multiplies
+/// `y^j₀ · x^j₁ · π(x)^j₂ · π²(x)^j₃ ⋯` where `jₖ` is bit `k` of `j`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CirclePolynomial {
+    coefficients: Vec<M31>,
+}
+
+impl CirclePolynomial {
+    pub fn new(coefficients: Vec<M31>) -> Self {
+        assert!(
+            coefficients.len().is_power_of_two(),
+            "number of coefficients must be a power of 2"
+        );
+        Self { coefficients }
+    }
+
+    pub fn interpolate(domain: &CircleDomain, evaluations: &[M31]) -> Self {
+        Self::new(domain.ifft(evaluations))
+    }
+
+    pub fn coefficients(&self) -> &[M31] {
+        &self.coefficients
+    }
+
+    pub fn log_size(&self) -> u32 {
+        self.coefficients.len().trailing_zeros()
+    }
+
+    /// Evaluates over `domain`, which may be larger than the polynomial for
+    /// a low-degree extension.
+    pub fn evaluate_over(&self, domain: &CircleDomain) -> Vec<M31> {
+        domain.fft(&self.coefficients)
+    }
+
+    /// Evaluates at any point of the circle by folding the highest basis
+    /// factor first.
+    pub fn evaluate(&self, point: CirclePoint) -> M31 {
+        let mut factors = vec![point.y];
+        let mut x = point.x;
+        for _ in 1..self.log_size() {
+            factors.push(x);
+            x = double_x(x);
+        }
+
+        let mut values = self.coefficients.clone();
+        for factor in factors.into_iter().rev() {
+            let half = values.len() / 2;
+            values = (0..half)
+                .map(|j| values[j] + factor * values[j + half])
+                .collect();
+        }
+        values[0]
+    }
+}
+
+fn split_even_odd(values: &[M31]) -> (Vec<M31>, Vec<M31>) {
+    let even = values.iter().step_by(2).copied().collect();
+    let odd = values.iter().skip(1).step_by(2).copied().collect();
+    (even, odd)
+}
+
+fn interleave(even: &[M31], odd: &[M31]) -> Vec<M31> {
+    even.iter()
+        .zip(odd.iter())
+        .flat_map(|(&a, &b)| [a, b])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::rand::Rng;
+
+    fn random_values(n: usize) -> Vec<M31> {
+        let mut rng = ark_std::test_rng();
+        (0..n)
+            .map(|_| M31::new(rng.gen_range(0..m31::MODULUS)))
+            .collect()
+    }
+
+    #[test]
+    fn test_generator_order() {
+        let generator = CirclePoint::generator();
+        assert!(generator.is_on_circle());
+        assert_eq!(generator.repeated_double(31), CirclePoint::identity());
+        assert_ne!(generator.repeated_double(30), CirclePoint::identity());
+        assert_eq!(generator + generator.conjugate(), CirclePoint::identity());
+    }
+
+    #[test]
+    fn test_domain_structure() {
+        let domain = CircleDomain::new(4);
+        let n = domain.size();
+        assert_eq!(n, 16);
+
+        for (i, point) in domain.points().iter().enumerate() {
+            assert!(point.is_on_circle());
+            assert!(!point.y.is_zero());
+            assert_eq!(domain.at(n - 1 - i), point.conjugate());
+            assert!(domain.points()[..i].iter().all(|other| other != point));
+        }
+        // first-layer x-coordinates pair up with their negatives
+        for i in 0..n / 2 {
+            assert_eq!(domain.at(n / 2 - 1 - i).x, -domain.at(i).x);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for log_size in 1..=8 {
+            let domain = CircleDomain::new(log_size);
+            let evaluations = random_values(domain.size());
+            let coefficients = domain.ifft(&evaluations);
+            assert_eq!(
+                domain.fft(&coefficients),
+                evaluations,
+                "log size {}",
+                log_size
+            );
+        }
+    }
+
+    #[test]
+    fn test_fft_matches_pointwise_evaluation() {
+        let domain = CircleDomain::new(5);
+        let poly = CirclePolynomial::new(random_values(32));
+        let evaluations = poly.evaluate_over(&domain);
+
+        for (point, value) in domain.points().iter().zip(evaluations) {
+            assert_eq!(poly.evaluate(*point), value);
+        }
+    }
+
+    #[test]
+    fn test_low_degree_extension() {
+        let small = CircleDomain::new(4);
+        let large = CircleDomain::new(6);
+        let poly = CirclePolynomial::interpolate(&small, &random_values(16));
+
+        let extended = poly.evaluate_over(&large);
+        for (point, value) in large.points().iter().zip(extended.iter()) {
+            assert_eq!(poly.evaluate(*point), *value);
+        }
+        // the extension interpolates back to the same coefficients
+        let recovered = large.ifft(&extended);
+        assert_eq!(&recovered[..16], poly.coefficients());
+        assert!(recovered[16..].iter().all(M31::is_zero));
+    }
+
+    #[test]
+    fn test_basis_functions() {
+        // coefficient 1 is y, coefficient 2 is x, coefficient 4 is 2x² - 1
+        let domain = CircleDomain::new(3);
+        for (index, expected) in [
+            (1, domain.points().iter().map(|p| p.y).collect::<Vec<_>>()),
+            (2, domain.points().iter().map(|p| p.x).collect()),
+            (4, domain.points().iter().map(|p| double_x(p.x)).collect()),
+        ] {
+            let mut coefficients = vec![M31::ZERO; 8];
+            coefficients[index] = M31::ONE;
+            assert_eq!(domain.fft(&coefficients), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "circle domain size must be between 2 and 2^30")]
+    fn test_domain_too_small() {
+        CircleDomain::new(0);
+    }
+}
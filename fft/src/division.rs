@@ -0,0 +1,281 @@
+use crate::domain::EvaluationDomain;
+use crate::ntt::{FFT_MUL_THRESHOLD, multiply_polynomials};
+use ark_ff::FftField;
+
+/// Divides `poly` by `(X - z)` with synthetic division, returning the
+/// quotient and the remainder `poly(z)`.
+pub fn divide_by_linear<F: FftField>(poly: &[F], z: F) -> (Vec<F>, F) {
+    if poly.is_empty() {
+        return (vec![], F::zero());
+    }
+
+    let mut quotient = vec![F::zero(); poly.len() - 1];
+    let mut carry = F::zero();
+    for i in (0..poly.len()).rev() {
+        carry = poly[i] + carry * z;
+        if i > 0 {
+            quotient[i - 1] = carry;
+        }
+    }
+    (quotient, carry)
+}
+
+/// Divides `poly` by the vanishing polynomial of `domain`, working in
+/// evaluation form on a coset of a domain large enough to hold `poly`,
+/// whose size is `domain.size()` times a power of 2 so mixed-radix domains
+/// divide too.
+///
+/// Panics if `poly` is not a multiple of the vanishing polynomial.
+pub fn divide_by_vanishing_poly<F: FftField>(poly: &[F], domain: &EvaluationDomain<F>) -> Vec<F> {
+    let poly = trimmed(poly);
+    if poly.is_empty() {
+        return vec![];
+    }
+    assert!(
+        poly.len() > domain.size(),
+        "polynomial is not divisible by the vanishing polynomial"
+    );
+
+    // a point x of the coset is a root of Xⁿ - offsetⁿ only if (x/offset)ᴺ = 1
+    // for the coset size N, which the multiplicative generator never satisfies
+    let mut size = domain.size();
+    while size < poly.len() {
+        size *= 2;
+    }
+    let coset = EvaluationDomain::new_mixed_radix(size).get_coset(F::GENERATOR * domain.offset());
+    let mut evaluations = coset.fft(poly);
+    domain.divide_by_vanishing_poly_on_coset(&mut evaluations, &coset);
+
+    let mut quotient = coset.ifft(&evaluations);
+    let quotient_len = poly.len() - domain.size();
+    assert!(
+        quotient[quotient_len..].iter().all(|c| c.is_zero()),
+        "polynomial is not divisible by the vanishing polynomial"
+    );
+    quotient.truncate(quotient_len);
+    quotient
+}
+
+/// Power series inverse of `poly` modulo `Xⁿ` by Newton iteration:
+/// `g ← g·(2 - poly·g) mod X²ᵏ`, doubling the precision each step.
+pub fn inverse_mod_xn<F: FftField>(poly: &[F], n: usize) -> Vec<F> {
+    assert!(
+        !poly.is_empty() && !poly[0].is_zero(),
+        "constant term must be invertible"
+    );
+
+    let mut inverse = vec![poly[0].inverse().unwrap()];
+    let mut precision = 1;
+    while precision < n {
+        precision = (2 * precision).min(n);
+
+        let truncated = &poly[..poly.len().min(precision)];
+        let mut error = multiply(truncated, &inverse);
+        error.truncate(precision);
+        error.iter_mut().for_each(|c| *c = -*c);
+        error[0] += F::from(2u64);
+
+        inverse = multiply(&inverse, &error);
+        inverse.truncate(precision);
+    }
+
+    inverse.resize(n, F::zero());
+    inverse
+}
+
+/// Long division `dividend = quotient·divisor + remainder` with
+/// `deg(remainder) < deg(divisor)`, using the reversed-polynomial trick so
+/// the quotient costs one power series inversion and two products.
+pub fn divide_with_remainder<F: FftField>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+    let divisor = trimmed(divisor);
+    assert!(!divisor.is_empty(), "division by the zero polynomial");
+    let dividend = trimmed(dividend);
+
+    if dividend.len() < divisor.len() {
+        return (vec![], dividend.to_vec());
+    }
+
+    // rev(q) = rev(a)·rev(b)⁻¹ mod X^(deg a - deg b + 1)
+    let quotient_len = dividend.len() - divisor.len() + 1;
+    let rev_dividend: Vec<F> = dividend.iter().rev().take(quotient_len).copied().collect();
+    let rev_divisor: Vec<F> = divisor.iter().rev().copied().collect();
+
+    let mut quotient = multiply(&rev_dividend, &inverse_mod_xn(&rev_divisor, quotient_len));
+    quotient.truncate(quotient_len);
+    quotient.reverse();
+
+    let product = multiply(&quotient, divisor);
+    let mut remainder: Vec<F> = dividend
+        .iter()
+        .zip(product.iter())
+        .take(divisor.len() - 1)
+        .map(|(a, b)| *a - b)
+        .collect();
+    remainder.truncate(trimmed(&remainder).len());
+
+    (quotient, remainder)
+}
+
+/// Product of two coefficient vectors, of length `a.len() + b.len() - 1`.
+pub(crate) fn multiply<F: FftField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let result_len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) >= FFT_MUL_THRESHOLD {
+        return multiply_polynomials(a, b);
+    }
+
+    let mut result = vec![F::zero(); result_len];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] += *x * y;
+        }
+    }
+    result
+}
+
+fn trimmed<F: FftField>(poly: &[F]) -> &[F] {
+    let len = poly.iter().rposition(|c| !c.is_zero()).map_or(0, |i| i + 1);
+    &poly[..len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_377::Fr;
+    use ark_ff::{Field, One, UniformRand, Zero};
+
+    fn to_field(input: Vec<i64>) -> Vec<Fr> {
+        input.into_iter().map(Fr::from).collect()
+    }
+
+    fn random_values(n: usize) -> Vec<Fr> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    fn evaluate(coefficients: &[Fr], x: Fr) -> Fr {
+        coefficients
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn test_divide_by_linear() {
+        // -6 - x + 2x² = (x - 2)(2x + 3)
+        let (quotient, remainder) = divide_by_linear(&to_field(vec![-6, -1, 2]), Fr::from(2));
+        assert_eq!(quotient, to_field(vec![3, 2]));
+        assert_eq!(remainder, Fr::zero());
+    }
+
+    #[test]
+    fn test_divide_by_linear_with_remainder() {
+        let poly = random_values(10);
+        let z = Fr::from(17);
+        let (quotient, remainder) = divide_by_linear(&poly, z);
+
+        assert_eq!(remainder, evaluate(&poly, z));
+        let x = Fr::from(5);
+        assert_eq!(
+            evaluate(&quotient, x) * (x - z) + remainder,
+            evaluate(&poly, x)
+        );
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_poly() {
+        let domain = EvaluationDomain::<Fr>::new(8);
+        let quotient = random_values(13);
+        let poly = multiply(&quotient, &domain.vanishing_polynomial());
+
+        assert_eq!(divide_by_vanishing_poly(&poly, &domain), quotient);
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_poly_on_coset_domain() {
+        // the divisor's own domain is the coset a fixed generator offset would pick
+        for offset in [Fr::GENERATOR, Fr::GENERATOR.square()] {
+            let domain = EvaluationDomain::<Fr>::new(8).get_coset(offset);
+            let quotient = random_values(13);
+            let poly = multiply(&quotient, &domain.vanishing_polynomial());
+
+            assert_eq!(divide_by_vanishing_poly(&poly, &domain), quotient);
+        }
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_poly_on_mixed_radix_domain() {
+        // 48 = 3·2⁴, and the products need cosets of 96 and 192 points
+        let domain = EvaluationDomain::<Fr>::new_mixed_radix(48);
+        assert_eq!(domain.size(), 48);
+        let x_plus_one = [Fr::one(), Fr::one()];
+        let poly = multiply(&x_plus_one, &domain.vanishing_polynomial());
+        assert_eq!(divide_by_vanishing_poly(&poly, &domain), x_plus_one);
+
+        let quotient = random_values(100);
+        let poly = multiply(&quotient, &domain.vanishing_polynomial());
+        assert_eq!(divide_by_vanishing_poly(&poly, &domain), quotient);
+    }
+
+    #[test]
+    #[should_panic(expected = "polynomial is not divisible by the vanishing polynomial")]
+    fn test_divide_by_vanishing_poly_not_divisible() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let mut poly = multiply(&random_values(4), &domain.vanishing_polynomial());
+        poly[0] += Fr::one();
+        divide_by_vanishing_poly(&poly, &domain);
+    }
+
+    #[test]
+    fn test_inverse_mod_xn() {
+        let poly = random_values(40);
+        let inverse = inverse_mod_xn(&poly, 50);
+        assert_eq!(inverse.len(), 50);
+
+        let product = multiply(&poly, &inverse);
+        assert_eq!(product[0], Fr::one());
+        assert!(product[1..50].iter().all(|c| c.is_zero()));
+    }
+
+    #[test]
+    fn test_divide_with_remainder() {
+        let dividend = random_values(100);
+        let divisor = random_values(37);
+        let (quotient, remainder) = divide_with_remainder(&dividend, &divisor);
+
+        assert_eq!(quotient.len(), 64);
+        assert!(remainder.len() < divisor.len());
+
+        let mut recombined = multiply(&quotient, &divisor);
+        for (i, r) in remainder.iter().enumerate() {
+            recombined[i] += r;
+        }
+        assert_eq!(recombined, dividend);
+    }
+
+    #[test]
+    fn test_divide_with_remainder_exact() {
+        // (x² - 1) / (x + 1) = x - 1
+        let (quotient, remainder) =
+            divide_with_remainder(&to_field(vec![-1, 0, 1]), &to_field(vec![1, 1]));
+        assert_eq!(quotient, to_field(vec![-1, 1]));
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn test_divide_with_remainder_smaller_dividend() {
+        let (quotient, remainder) =
+            divide_with_remainder(&to_field(vec![1, 2, 0]), &to_field(vec![1, 1, 1]));
+        assert!(quotient.is_empty());
+        assert_eq!(remainder, to_field(vec![1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn test_divide_by_zero_polynomial() {
+        divide_with_remainder(&to_field(vec![1, 2]), &to_field(vec![0]));
+    }
+}
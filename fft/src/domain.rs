@@ -0,0 +1,485 @@
+use crate::mixed_radix::{MixedRadixPlan, smallest_supported_size};
+use crate::ntt::NttPlan;
+use ark_ff::{FftField, batch_inversion};
+use std::sync::Arc;
+
+/// A multiplicative subgroup `H = {ω⁰, ..., ωⁿ⁻¹}`, or a coset `g·H` of one,
+/// together with everything needed to move between coefficient and
+/// evaluation form over it.
+///
+/// [`Self::new`] builds power-of-two subgroups with radix-2 transforms;
+/// [`Self::new_mixed_radix`] also allows orders like `3·2ᵏ` where the field
+/// has them, using [`MixedRadixPlan`].
+#[derive(Debug, Clone)]
+pub struct EvaluationDomain<F: FftField> {
+    size: usize,
+    log_size: u32,
+    generator: F,
+    generator_inv: F,
+    size_inv: F,
+    offset: F,
+    offset_inv: F,
+    offset_pow_size: F,
+    plan: DomainPlan<F>,
+}
+
+#[derive(Debug, Clone)]
+enum DomainPlan<F: FftField> {
+    Radix2(Arc<NttPlan<F>>),
+    MixedRadix(Arc<MixedRadixPlan<F>>),
+}
+
+impl<F: FftField> EvaluationDomain<F> {
+    /// The smallest subgroup holding at least `num_coeffs` points.
+    pub fn new(num_coeffs: usize) -> Self {
+        let size = num_coeffs.max(1).next_power_of_two();
+        Self::with_plan(size, DomainPlan::Radix2(NttPlan::cached(size)))
+    }
+
+    /// The smallest subgroup of any order `2ᵃ·qᵇ` (see [`MixedRadixPlan`])
+    /// holding at least `num_coeffs` points, so a domain for 48 points has
+    /// 48 rather than 64. Power-of-two orders still use radix-2 transforms.
+    pub fn new_mixed_radix(num_coeffs: usize) -> Self {
+        let size = smallest_supported_size::<F>(num_coeffs.max(1))
+            .expect("Field must support a subgroup large enough for the domain");
+        if size.is_power_of_two() {
+            return Self::new(size);
+        }
+        Self::with_plan(size, DomainPlan::MixedRadix(MixedRadixPlan::cached(size)))
+    }
+
+    fn with_plan(size: usize, plan: DomainPlan<F>) -> Self {
+        let generator = F::get_root_of_unity(size as u64)
+            .expect("Field must support nth root of unity for FFT");
+
+        Self {
+            size,
+            log_size: size.ilog2(),
+            generator,
+            generator_inv: generator.inverse().unwrap(),
+            size_inv: F::from(size as u64).inverse().unwrap(),
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+            plan,
+        }
+    }
+
+    /// The coset `offset·H` of this domain's subgroup.
+    pub fn get_coset(&self, offset: F) -> Self {
+        assert!(!offset.is_zero(), "coset offset must be non-zero");
+        Self {
+            offset,
+            offset_inv: offset.inverse().unwrap(),
+            offset_pow_size: offset.pow([self.size as u64]),
+            ..self.clone()
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// `log₂` of the size, rounded down for mixed-radix domains.
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    pub fn generator(&self) -> F {
+        self.generator
+    }
+
+    pub fn generator_inv(&self) -> F {
+        self.generator_inv
+    }
+
+    pub fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    pub fn is_coset(&self) -> bool {
+        !self.offset.is_one()
+    }
+
+    /// The radix-2 plan, or `None` for a mixed-radix domain.
+    pub fn plan(&self) -> Option<&NttPlan<F>> {
+        match &self.plan {
+            DomainPlan::Radix2(plan) => Some(plan),
+            DomainPlan::MixedRadix(_) => None,
+        }
+    }
+
+    pub fn is_mixed_radix(&self) -> bool {
+        matches!(self.plan, DomainPlan::MixedRadix(_))
+    }
+
+    /// The ith point `g·ωⁱ` of the domain.
+    pub fn element(&self, i: usize) -> F {
+        self.offset * self.generator.pow([i as u64])
+    }
+
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = self.offset;
+        for _ in 0..self.size {
+            elements.push(current);
+            current *= self.generator;
+        }
+        elements
+    }
+
+    /// Evaluates the polynomial with the given coefficients over the domain.
+    pub fn fft(&self, coefficients: &[F]) -> Vec<F> {
+        let mut values = coefficients.to_vec();
+        self.fft_in_place(&mut values);
+        values
+    }
+
+    /// Pads `values` with zeros to the domain size and evaluates in place.
+    pub fn fft_in_place(&self, values: &mut Vec<F>) {
+        assert!(
+            values.len() <= self.size,
+            "polynomial has more coefficients than the domain has points"
+        );
+        values.resize(self.size, F::zero());
+        if self.is_coset() {
+            distribute_powers(values, self.offset);
+        }
+        match &self.plan {
+            DomainPlan::Radix2(plan) => plan.forward(values),
+            DomainPlan::MixedRadix(plan) => plan.forward(values),
+        }
+    }
+
+    /// Interpolates evaluations over the domain back to coefficients.
+    pub fn ifft(&self, evaluations: &[F]) -> Vec<F> {
+        let mut values = evaluations.to_vec();
+        self.ifft_in_place(&mut values);
+        values
+    }
+
+    pub fn ifft_in_place(&self, values: &mut [F]) {
+        assert_eq!(
+            values.len(),
+            self.size,
+            "expected one evaluation per domain point"
+        );
+        match &self.plan {
+            DomainPlan::Radix2(plan) => plan.inverse(values),
+            DomainPlan::MixedRadix(plan) => plan.inverse(values),
+        }
+        if self.is_coset() {
+            distribute_powers(values, self.offset_inv);
+        }
+    }
+
+    /// Evaluates over the coset `offset·H` without building a coset domain.
+    pub fn coset_fft(&self, coefficients: &[F], offset: F) -> Vec<F> {
+        self.get_coset(self.offset * offset).fft(coefficients)
+    }
+
+    /// Interpolates evaluations over the coset `offset·H`.
+    pub fn coset_ifft(&self, evaluations: &[F], offset: F) -> Vec<F> {
+        self.get_coset(self.offset * offset).ifft(evaluations)
+    }
+
+    /// `Z(x) = xⁿ - gⁿ`, which is `xⁿ - 1` for the subgroup itself.
+    pub fn evaluate_vanishing_polynomial(&self, x: F) -> F {
+        x.pow([self.size as u64]) - self.offset_pow_size
+    }
+
+    /// Coefficients of the vanishing polynomial, in ascending degree.
+    pub fn vanishing_polynomial(&self) -> Vec<F> {
+        let mut coefficients = vec![F::zero(); self.size + 1];
+        coefficients[0] = -self.offset_pow_size;
+        coefficients[self.size] = F::one();
+        coefficients
+    }
+
+    /// Evaluates the ith Lagrange basis polynomial of the domain at `tau`.
+    pub fn evaluate_lagrange_basis(&self, i: usize, tau: F) -> F {
+        let x_i = self.element(i);
+        let z = self.evaluate_vanishing_polynomial(tau);
+        if z.is_zero() {
+            return if tau == x_i { F::one() } else { F::zero() };
+        }
+
+        // Lᵢ(τ) = Z(τ)·xᵢ / (n·gⁿ·(τ - xᵢ))
+        let denominator = F::from(self.size as u64) * self.offset_pow_size * (tau - x_i);
+        z * x_i * denominator.inverse().unwrap()
+    }
+
+    /// Evaluates every Lagrange basis polynomial of the domain at `tau` with a
+    /// single batch inversion.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        let z = self.evaluate_vanishing_polynomial(tau);
+        let elements = self.elements();
+
+        if z.is_zero() {
+            return elements
+                .iter()
+                .map(|x_i| if *x_i == tau { F::one() } else { F::zero() })
+                .collect();
+        }
+
+        let mut denominators: Vec<F> = elements.iter().map(|x_i| tau - x_i).collect();
+        batch_inversion(&mut denominators);
+
+        let scale = z * self.size_inv * self.offset_pow_size.inverse().unwrap();
+        elements
+            .iter()
+            .zip(denominators.iter())
+            .map(|(x_i, inv)| scale * x_i * inv)
+            .collect()
+    }
+
+    /// Divides evaluations over `coset` by this domain's vanishing polynomial.
+    ///
+    /// The coset's size must be a multiple of this domain's, so `Z(x)` takes
+    /// only `coset.size() / self.size()` distinct values on it; those are
+    /// inverted once and reused.
+    pub fn divide_by_vanishing_poly_on_coset(&self, evaluations: &mut [F], coset: &Self) {
+        assert_eq!(
+            evaluations.len(),
+            coset.size,
+            "expected one evaluation per coset point"
+        );
+        assert!(
+            coset.size >= self.size && coset.size.is_multiple_of(self.size),
+            "coset size must be a multiple of the domain size"
+        );
+
+        let ratio = coset.size / self.size;
+        let mut z_values: Vec<F> = coset.elements()[..ratio]
+            .iter()
+            .map(|x| self.evaluate_vanishing_polynomial(*x))
+            .collect();
+        assert!(
+            z_values.iter().all(|z| !z.is_zero()),
+            "coset intersects the domain"
+        );
+        batch_inversion(&mut z_values);
+
+        for (i, evaluation) in evaluations.iter_mut().enumerate() {
+            *evaluation *= z_values[i % ratio];
+        }
+    }
+}
+
+/// Multiplies the ith value by `gⁱ`.
+fn distribute_powers<F: FftField>(values: &mut [F], g: F) {
+    let mut power = F::one();
+    for value in values.iter_mut() {
+        *value *= power;
+        power *= g;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_377::Fr;
+    use ark_ff::{Field, One, UniformRand, Zero};
+
+    fn evaluate(coefficients: &[Fr], x: Fr) -> Fr {
+        coefficients
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, c| acc * x + c)
+    }
+
+    fn random_values(n: usize) -> Vec<Fr> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_new_rounds_up() {
+        let domain = EvaluationDomain::<Fr>::new(5);
+        assert_eq!(domain.size(), 8);
+        assert_eq!(domain.log_size(), 3);
+        assert_eq!(domain.generator().pow([8]), Fr::one());
+        assert_eq!(domain.generator() * domain.generator_inv(), Fr::one());
+        assert_eq!(domain.size_inv() * Fr::from(8), Fr::one());
+    }
+
+    #[test]
+    fn test_fft_pads_and_round_trips() {
+        let domain = EvaluationDomain::<Fr>::new(8);
+        let coefficients = random_values(5);
+        let evaluations = domain.fft(&coefficients);
+
+        for (i, x) in domain.elements().into_iter().enumerate() {
+            assert_eq!(evaluations[i], evaluate(&coefficients, x));
+        }
+
+        let mut expected = coefficients.clone();
+        expected.resize(8, Fr::zero());
+        assert_eq!(domain.ifft(&evaluations), expected);
+    }
+
+    #[test]
+    fn test_coset_fft() {
+        let domain = EvaluationDomain::<Fr>::new(16);
+        let offset = Fr::GENERATOR;
+        let coefficients = random_values(16);
+
+        let evaluations = domain.coset_fft(&coefficients, offset);
+        let coset = domain.get_coset(offset);
+        for (i, x) in coset.elements().into_iter().enumerate() {
+            assert_eq!(x, offset * domain.element(i));
+            assert_eq!(evaluations[i], evaluate(&coefficients, x));
+        }
+
+        assert_eq!(coset.fft(&coefficients), evaluations);
+        assert_eq!(domain.coset_ifft(&evaluations, offset), coefficients);
+        assert_eq!(coset.ifft(&evaluations), coefficients);
+    }
+
+    #[test]
+    fn test_mixed_radix_domain() {
+        use ark_bn254::Fr;
+
+        let domain = EvaluationDomain::<Fr>::new_mixed_radix(40);
+        assert_eq!(domain.size(), 48);
+        assert!(domain.is_mixed_radix() && domain.plan().is_none());
+        assert!(!EvaluationDomain::<Fr>::new_mixed_radix(60).is_mixed_radix());
+
+        let mut rng = ark_std::test_rng();
+        let coefficients: Vec<Fr> = (0..40).map(|_| Fr::rand(&mut rng)).collect();
+        let evaluate = |x: Fr| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(Fr::zero(), |acc, c| acc * x + c)
+        };
+        let mut padded = coefficients.clone();
+        padded.resize(48, Fr::zero());
+
+        for domain in [domain.clone(), domain.get_coset(Fr::GENERATOR)] {
+            let evaluations = domain.fft(&coefficients);
+            for (i, x) in domain.elements().into_iter().enumerate() {
+                assert_eq!(evaluations[i], evaluate(x));
+            }
+            assert_eq!(domain.ifft(&evaluations), padded);
+        }
+        assert_eq!(
+            domain.vanishing_polynomial().len(),
+            49,
+            "X⁴⁸ - 1 vanishes on the domain"
+        );
+        assert!(
+            domain
+                .elements()
+                .iter()
+                .all(|x| domain.evaluate_vanishing_polynomial(*x).is_zero())
+        );
+    }
+
+    #[test]
+    fn test_vanishing_polynomial() {
+        let domain = EvaluationDomain::<Fr>::new(8);
+        for x in domain.elements() {
+            assert!(domain.evaluate_vanishing_polynomial(x).is_zero());
+        }
+
+        let x = Fr::from(7);
+        assert_eq!(
+            domain.evaluate_vanishing_polynomial(x),
+            evaluate(&domain.vanishing_polynomial(), x)
+        );
+
+        let coset = domain.get_coset(Fr::GENERATOR);
+        for x in coset.elements() {
+            assert!(coset.evaluate_vanishing_polynomial(x).is_zero());
+            assert!(!domain.evaluate_vanishing_polynomial(x).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_lagrange_coefficients_interpolate() {
+        let domain = EvaluationDomain::<Fr>::new(8);
+        let coefficients = random_values(8);
+        let evaluations = domain.fft(&coefficients);
+        let tau = Fr::from(12345);
+
+        let lagrange = domain.evaluate_all_lagrange_coefficients(tau);
+        let interpolated: Fr = lagrange
+            .iter()
+            .zip(evaluations.iter())
+            .map(|(l, e)| *l * e)
+            .sum();
+        assert_eq!(interpolated, evaluate(&coefficients, tau));
+        assert_eq!(lagrange.iter().sum::<Fr>(), Fr::one());
+
+        for (i, l) in lagrange.iter().enumerate() {
+            assert_eq!(*l, domain.evaluate_lagrange_basis(i, tau));
+        }
+    }
+
+    #[test]
+    fn test_lagrange_coefficients_on_coset() {
+        let coset = EvaluationDomain::<Fr>::new(4).get_coset(Fr::from(3));
+        let coefficients = random_values(4);
+        let evaluations = coset.fft(&coefficients);
+        let tau = Fr::from(10);
+
+        let lagrange = coset.evaluate_all_lagrange_coefficients(tau);
+        let interpolated: Fr = lagrange
+            .iter()
+            .zip(evaluations.iter())
+            .map(|(l, e)| *l * e)
+            .sum();
+        assert_eq!(interpolated, evaluate(&coefficients, tau));
+    }
+
+    #[test]
+    fn test_lagrange_coefficients_at_domain_point() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let lagrange = domain.evaluate_all_lagrange_coefficients(domain.element(2));
+        assert_eq!(
+            lagrange,
+            vec![Fr::zero(), Fr::zero(), Fr::one(), Fr::zero()]
+        );
+        assert_eq!(
+            domain.evaluate_lagrange_basis(1, domain.element(2)),
+            Fr::zero()
+        );
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_poly_on_coset() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let quotient = random_values(4);
+
+        // p = Z_H · q has degree 7, so evaluate it on a coset of size 8
+        let z = domain.vanishing_polynomial();
+        let mut p = vec![Fr::zero(); 8];
+        for (i, zi) in z.iter().enumerate() {
+            for (j, qj) in quotient.iter().enumerate() {
+                p[i + j] += *zi * qj;
+            }
+        }
+
+        let coset = EvaluationDomain::<Fr>::new(8).get_coset(Fr::GENERATOR);
+        let mut evaluations = coset.fft(&p);
+        domain.divide_by_vanishing_poly_on_coset(&mut evaluations, &coset);
+
+        let mut expected = quotient.clone();
+        expected.resize(8, Fr::zero());
+        assert_eq!(coset.ifft(&evaluations), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "coset intersects the domain")]
+    fn test_divide_on_subgroup_panics() {
+        let domain = EvaluationDomain::<Fr>::new(4);
+        let subgroup = EvaluationDomain::<Fr>::new(8);
+        let mut evaluations = vec![Fr::one(); 8];
+        domain.divide_by_vanishing_poly_on_coset(&mut evaluations, &subgroup);
+    }
+}
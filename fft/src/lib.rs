@@ -1,4 +1,15 @@
+pub mod division;
+pub mod additive;
+pub mod binary_field;
+pub mod circle;
+pub mod domain;
+pub mod mixed_radix;
+pub mod ntt;
+pub mod reed_solomon;
+pub mod signal;
+
 use ark_ff::FftField;
+use ntt::NttPlan;
 use std::marker::PhantomData;
 
 /// Polynomial operations using Fast Fourier Transform over finite fields.
@@ -10,95 +21,22 @@ pub struct PolynomialFFT<F: FftField> {
 }
 
 impl<F: FftField> PolynomialFFT<F> {
-    fn split_even_odd(sequence: &[F]) -> (Vec<F>, Vec<F>) {
-        let even_indexed: Vec<F> = sequence
-            .iter()
-            .step_by(2)
-            .copied()
-            .collect();
-
-        let odd_indexed: Vec<F> = sequence
-            .iter()
-            .skip(1)
-            .step_by(2)
-            .copied()
-            .collect();
-
-        (even_indexed, odd_indexed)
-    }
-
-    /// Performs the Cooley-Tukey FFT algorithm over a finite field.
-    /// * `inverse` - If true, performs inverse FFT (IFFT)
-
-    fn cooley_tukey_fft(sequence: &[F], inverse: bool) -> Vec<F> {
-        let n = sequence.len();
-
-        if n == 0 || !n.is_power_of_two() {
-            panic!("FFT input length must be a power of 2 and non-zero");
-        }
-
-        // Base case: single element is already transformed
-        if n == 1 {
-            return sequence.to_vec();
-        }
-
-        // Divide: split into even and odd subsequences
-        let (even_seq, odd_seq) = Self::split_even_odd(sequence);
-
-        // Conquer: recursively compute FFT of both halves
-        let even_fft = Self::cooley_tukey_fft(&even_seq, inverse);
-        let odd_fft = Self::cooley_tukey_fft(&odd_seq, inverse);
-
-        // Get the primitive nth root of unity in the field
-        let root_of_unity = F::get_root_of_unity(n as u64)
-            .expect("Field must support nth root of unity for FFT");
-
-        // For inverse FFT, use ω⁻¹ instead of ω
-        let omega = if inverse {
-            root_of_unity
-                .inverse()
-                .expect("Root of unity must be invertible")
-        } else {
-            root_of_unity
-        };
-
-        // Combine: butterfly operations to merge results
-        let mut result = vec![F::zero(); n];
-        let half_n = n / 2;
-
-        for k in 0..half_n {
-            // Twiddle factor: ωᵏ
-            let twiddle_factor = omega.pow([k as u64]);
-
-            // Butterfly operation:
-            // y[k] = even[k] + ωᵏ · odd[k]
-            // y[k + n/2] = even[k] - ωᵏ · odd[k]
-            let twiddle_product = twiddle_factor * odd_fft[k];
-
-            result[k] = even_fft[k] + twiddle_product;
-            result[k + half_n] = even_fft[k] - twiddle_product;
-        }
-
-        result
-    }
-
     /// Converts polynomial from coefficient representation to evaluation representation.
     /// Given polynomial p(x) = c₀ + c₁x + c₂x² + ... + cₙ₋₁xⁿ⁻¹,
     /// computes evaluations at the nth roots of unity: [p(ω⁰), p(ω¹), ..., p(ωⁿ⁻¹)]
     pub fn forward_fft(coefficients: &[F]) -> Vec<F> {
-        Self::cooley_tukey_fft(coefficients, false)
+        let mut values = coefficients.to_vec();
+        NttPlan::cached(values.len()).forward(&mut values);
+        values
     }
 
     /// Converts polynomial from evaluation representation back to coefficient representation.
     /// Given evaluations [p(ω⁰), p(ω¹), ..., p(ωⁿ⁻¹)],
     /// recovers the coefficients [c₀, c₁, c₂, ..., cₙ₋₁]
-
     pub fn inverse_fft(evaluations: &[F]) -> Vec<F> {
-        let n = evaluations.len();
-        Self::cooley_tukey_fft(evaluations, true)
-            .iter()
-            .map(|&coefficient| coefficient / F::from(n as u64))
-            .collect()
+        let mut values = evaluations.to_vec();
+        NttPlan::cached(values.len()).inverse(&mut values);
+        values
     }
 
     /// Multiplies two polynomials efficiently using FFT.
@@ -116,34 +54,7 @@ impl<F: FftField> PolynomialFFT<F> {
             return vec![F::zero()];
         }
 
-        // Result degree is deg(a) + deg(b)
-        let result_length = poly_a.len() + poly_b.len() - 1;
-
-        // Find next power of 2 for efficient FFT
-        let fft_size = result_length.next_power_of_two();
-        dbg!(fft_size);
-
-        // Pad polynomials with zeros
-        let mut padded_a = poly_a.to_vec();
-        let mut padded_b = poly_b.to_vec();
-        padded_a.resize(fft_size, F::zero());
-        padded_b.resize(fft_size, F::zero());
-
-        // Transform to evaluation domain
-        let evals_a = Self::forward_fft(&padded_a);
-        let evals_b = Self::forward_fft(&padded_b);
-
-        // Point-wise multiplication (O(n) instead of O(n²))
-        let product_evals: Vec<F> = evals_a
-            .iter()
-            .zip(evals_b.iter())
-            .map(|(&a, &b)| a * b)
-            .collect();
-
-        // Transform back to coefficient domain
-        let result = Self::inverse_fft(&product_evals);
-        // result.truncate(result_length);
-        result
+        ntt::multiply_polynomials(poly_a, poly_b)
     }
 }
 
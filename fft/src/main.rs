@@ -1,173 +1,33 @@
-use std::f64::consts::PI;
-
-// Complex number struct (simple version)
-#[derive(Clone, Copy, Debug)]
-struct Complex {
-    re: f64,
-    im: f64,
-}
-
-impl Complex {
-    fn new(re: f64, im: f64) -> Self {
-        Complex { re, im }
-    }
-
-    fn add(self, other: Self) -> Self {
-        Complex::new(self.re + other.re, self.im + other.im)
-    }
-
-    fn sub(self, other: Self) -> Self {
-        Complex::new(self.re - other.re, self.im - other.im)
-    }
-
-    fn mul(self, other: Self) -> Self {
-        Complex::new(
-            self.re * other.re - self.im * other.im,
-            self.re * other.im + self.im * other.re,
-        )
-    }
-
-    fn div(self, other: Self) -> Self {
-        let denom = other.re * other.re + other.im * other.im;
-        Complex::new(
-            (self.re * other.re + self.im * other.im) / denom,
-            (self.im * other.re - self.re * other.im)/ denom
-        )
-    }
-}
-
-fn fft(a: &[Complex], inverse: bool) -> Vec<Complex> {
-    let n = a.len();
-    if n == 0 || !n.is_power_of_two() {
-        panic!("FFT input length must be a power of 2 and non-zero");
-    }
-    if n <= 1 {
-        return a.to_vec();
-    }
-
-    // Split into even and odd
-    let even: Vec<Complex> = a.iter().step_by(2).copied().collect();
-    let odd: Vec<Complex> = a.iter().skip(1).step_by(2).copied().collect();
-
-    let even_fft = fft(&even, inverse);
-    let odd_fft = fft(&odd, inverse);
-
-    // Twiddle factors
-    let sign = if inverse { -1.0 } else { 1.0 };
-    let mut wk = vec![Complex::new(0.0, 0.0); n / 2];
-    for k in 0..n/2 {
-        let angle = sign * 2.0 * PI * k as f64 / n as f64;
-        wk[k] = Complex::new(angle.cos(), angle.sin());
-    }
-
-    // Combine with butterfly magic
-    let mut result = vec![Complex::new(0.0, 0.0); n];
-    for k in 0..n/2 {
-        let temp = wk[k].mul(odd_fft[k]);
-        result[k] = even_fft[k].add(temp);
-        result[k + n/2] = even_fft[k].sub(temp);
-    }
-
-    result
-}
-
-fn multiply_polynomials(p1: &[f64], p2: &[f64]) -> Vec<f64> {
-    let deg1 = p1.len().saturating_sub(1);
-    let deg2 = p2.len().saturating_sub(1);
-    let product_deg = deg1 + deg2;
-    let mut n = 1;
-    while n <= product_deg {
-        n *= 2;
-    }
-
-    // Pad to power of 2 with zeros
-    let mut a: Vec<Complex> = p1.iter().map(|&x| Complex::new(x, 0.0)).collect();
-    a.resize(n, Complex::new(0.0, 0.0));
-
-    let mut b: Vec<Complex> = p2.iter().map(|&x| Complex::new(x, 0.0)).collect();
-    b.resize(n, Complex::new(0.0, 0.0));
-
-    // Forward FFT
-    let a_fft = fft(&a, false);
-    let b_fft = fft(&b, false);
-
-    // Point-wise multiply
-    let mut prod_fft = vec![Complex::new(0.0, 0.0); n];
-    for i in 0..n {
-        prod_fft[i] = a_fft[i].mul(b_fft[i]);
-    }
-
-    // Inverse FFT
-    let prod = fft(&prod_fft, true);
-
-    // Normalize (divide by n) and round to integers
-    let mut result: Vec<f64> = prod.iter()
-        .map(|c| (c.re / n as f64).round())
-        .collect();
-
-    // Trim trailing zeros
-    while result.len() > 1 && result.last().unwrap().abs() < 1e-6 {
-        result.pop();
-    }
-
-
-    result
-}
-
-fn divide_by_linear(poly: &[f64], z: f64) -> Vec<f64> {
-    let n_orig = poly.len() - 1; // degree after division
-    let mut n = 1;
-    while n <= poly.len() {
-        n *= 2;
-    }
-
-    // pad polynomial
-    let mut a: Vec<Complex> = poly .iter().map(|&x| Complex::new(x, 0.0)).collect();
-    a.resize(n, Complex::new(0.0, 0.0));
-
-    // run fft on a
-    let a_fft = fft(&a, false);
-
-    // divide evaluations
-    let mut q_fft = vec![Complex::new(0.0, 0.0); n];
-    for i in 0..n {
-        let angle = 2.0 * PI * i as f64 / n as f64;
-        let wi = Complex::new(angle.cos(), angle.sin());
-        let denom = wi.sub(Complex::new(z, 0.0));
-        q_fft[i] = a_fft[i].div(denom)
-    }
-
-    // inverse fft
-    let q = fft(&q_fft, true);
-
-    // normalize and trim
-    q.iter()
-        .take(n_orig)
-        .map(|c| c.re / n as f64)
-        .collect()
-}
+use ark_bls12_377::Fr;
+use fft::division::divide_by_linear;
+use fft::signal::{Window, convolve, multiply_integer_polynomials, rfft};
+use num_complex::Complex64;
 
 fn main() {
-    let poly1 = vec![1.0, 2.0, 3.0]; // 1 + 2x + 3x²
-    let poly2 = vec![4.0, 5.0];      // 4 + 5x
+    let poly1 = vec![1, 2, 3]; // 1 + 2x + 3x²
+    let poly2 = vec![4, 5]; // 4 + 5x
 
-    let product = multiply_polynomials(&poly1, &poly2);
+    let product = multiply_integer_polynomials(&poly1, &poly2);
 
     println!("Product: {:?}", product);
-    // Prints: [4.0, 13.0, 22.0, 15.0]  Yay!
-
-    let poly3 = vec![-6.0, -1.0, 2.0]; // -6 -x + 2x²
-    let z = 2.0; // x - 2
-    let quotient = divide_by_linear(&poly3, z);
-    println!("Quotient: {:?}", quotient);
-    // Prints: [3.0, 2.0]  => 3 + 2x
-
-    let complex1 = Complex::new(2.0, -1.0);
-    let complex2 = Complex::new(2.0, 1.0);
-    let sum = complex1.add(complex2);
-    let prod = complex1.mul(complex2);
-    let div = complex1.div(complex2);
-    println!("Sum: {:?}", sum);
-    println!("Product: {:?}", prod);
-    println!("Division: {:?}", div);
-}
\ No newline at end of file
+    // Prints: [4, 13, 22, 15]  Yay!
+
+    let poly3: Vec<Fr> = vec![Fr::from(-6), Fr::from(-1), Fr::from(2)]; // -6 -x + 2x²
+    let z = Fr::from(2); // x - 2
+    let (quotient, remainder) = divide_by_linear(&poly3, z);
+    println!("Quotient: {:?}, remainder: {}", quotient, remainder);
+    // Quotient is 3 + 2x, remainder 0
+
+    let signal = vec![0.0, 1.0, 0.0, -1.0, 0.0, 1.0, 0.0, -1.0];
+    let mut windowed = signal.clone();
+    Window::Hann.apply(&mut windowed);
+    println!("Spectrum: {:?}", rfft(&signal));
+    println!("Windowed spectrum: {:?}", rfft(&windowed));
+    println!("Smoothed: {:?}", convolve(&signal, &[0.25, 0.5, 0.25]));
+
+    let complex1 = Complex64::new(2.0, -1.0);
+    let complex2 = Complex64::new(2.0, 1.0);
+    println!("Sum: {:?}", complex1 + complex2);
+    println!("Product: {:?}", complex1 * complex2);
+    println!("Division: {:?}", complex1 / complex2);
+}
@@ -0,0 +1,341 @@
+use crate::ntt::{cached_plan, convolve, powers, scale_in_place};
+use ark_ff::FftField;
+use std::sync::Arc;
+
+/// NTT plan for any size `2ᵃ·qᵇ` the field has a subgroup for, where `q` is
+/// `FftField::SMALL_SUBGROUP_BASE` (3 for BN254 and BLS12-381 `Fr`).
+///
+/// The transform is a recursive decimation-in-time Cooley–Tukey over the
+/// factorisation in `radices`: radix-4 stages first, then at most one
+/// radix-2 stage, then the odd factors.
+#[derive(Debug, Clone)]
+pub struct MixedRadixPlan<F: FftField> {
+    size: usize,
+    radices: Vec<usize>,
+    roots: Vec<F>,
+    inverse_roots: Vec<F>,
+    /// `radix_roots[level][e]` is the `e`th power of the primitive root of
+    /// order `radices[level]`, for the butterflies of that stage.
+    radix_roots: Vec<Vec<F>>,
+    inverse_radix_roots: Vec<Vec<F>>,
+    size_inv: F,
+}
+
+impl<F: FftField> MixedRadixPlan<F> {
+    pub fn new(size: usize) -> Self {
+        let omega = F::get_root_of_unity(size as u64)
+            .unwrap_or_else(|| panic!("field has no multiplicative subgroup of size {}", size));
+        let omega_inv = omega.inverse().expect("Root of unity must be invertible");
+
+        let radices = factorise(size);
+        let roots = powers(omega, size);
+        let inverse_roots = powers(omega_inv, size);
+        let stage_roots = |roots: &[F]| -> Vec<Vec<F>> {
+            radices
+                .iter()
+                .map(|&radix| (0..radix).map(|e| roots[e * (size / radix)]).collect())
+                .collect()
+        };
+
+        Self {
+            size,
+            radix_roots: stage_roots(&roots),
+            inverse_radix_roots: stage_roots(&inverse_roots),
+            radices,
+            roots,
+            inverse_roots,
+            size_inv: F::from(size as u64).inverse().unwrap(),
+        }
+    }
+
+    /// Returns the plan for `size`, building it on first use in this thread.
+    pub fn cached(size: usize) -> Arc<Self> {
+        cached_plan(size, Self::new)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn radices(&self) -> &[usize] {
+        &self.radices
+    }
+
+    /// Coefficients in natural order to evaluations in natural order.
+    pub fn forward(&self, values: &mut [F]) {
+        self.transform(values, &self.roots, &self.radix_roots);
+    }
+
+    /// Evaluations in natural order to coefficients in natural order.
+    pub fn inverse(&self, values: &mut [F]) {
+        self.transform(values, &self.inverse_roots, &self.inverse_radix_roots);
+        scale_in_place(values, self.size_inv);
+    }
+
+    fn transform(&self, values: &mut [F], roots: &[F], radix_roots: &[Vec<F>]) {
+        assert_eq!(
+            values.len(),
+            self.size,
+            "input length must match the plan size"
+        );
+
+        let input = values.to_vec();
+        let mut scratch = Vec::with_capacity(self.radices.iter().max().copied().unwrap_or(1));
+        self.transform_strided(&input, 1, values, roots, radix_roots, &mut scratch);
+    }
+
+    /// Writes the DFT of `input[0], input[stride], ...` into `output`, split
+    /// by the stages whose roots are in `radix_roots`. `scratch` is only used
+    /// after the sub-transforms return, so every level shares it.
+    fn transform_strided(
+        &self,
+        input: &[F],
+        stride: usize,
+        output: &mut [F],
+        roots: &[F],
+        radix_roots: &[Vec<F>],
+        scratch: &mut Vec<F>,
+    ) {
+        let n = output.len();
+        if n == 1 {
+            output[0] = input[0];
+            return;
+        }
+
+        let radix = radix_roots[0].len();
+        let m = n / radix;
+        for (j, chunk) in output.chunks_mut(m).enumerate() {
+            self.transform_strided(
+                &input[j * stride..],
+                stride * radix,
+                chunk,
+                roots,
+                &radix_roots[1..],
+                scratch,
+            );
+        }
+
+        // X[k + m·s] = Σⱼ (ωₙ^(jk) · Yⱼ[k]) · ωᵣ^(js)
+        let step = self.size / n;
+        scratch.resize(radix, F::zero());
+        for k in 0..m {
+            for (j, slot) in scratch.iter_mut().enumerate() {
+                *slot = output[j * m + k] * roots[j * k * step];
+            }
+            small_dft(scratch, &radix_roots[0]);
+            for (s, value) in scratch.iter().enumerate() {
+                output[k + m * s] = *value;
+            }
+        }
+    }
+}
+
+/// Smallest `n' >= n` the field has a multiplicative subgroup of, so that
+/// transforms avoid padding all the way to the next power of two.
+pub fn smallest_supported_size<F: FftField>(n: usize) -> Option<usize> {
+    let max_two_part = 1usize.checked_shl(F::TWO_ADICITY)?;
+    let mut odd_parts = vec![1usize];
+    if let (Some(base), Some(adicity)) = (F::SMALL_SUBGROUP_BASE, F::SMALL_SUBGROUP_BASE_ADICITY) {
+        let mut part = 1usize;
+        for _ in 0..adicity {
+            match part.checked_mul(base as usize) {
+                Some(next) => part = next,
+                None => break,
+            }
+            odd_parts.push(part);
+        }
+    }
+
+    odd_parts
+        .into_iter()
+        .filter_map(|odd| {
+            let two_part = n.div_ceil(odd).max(1).next_power_of_two();
+            if two_part > max_two_part {
+                return None;
+            }
+            two_part.checked_mul(odd)
+        })
+        .min()
+}
+
+/// Multiplies two polynomials in coefficient form on the smallest supported
+/// domain that holds the product, returning exactly `a.len() + b.len() - 1`
+/// coefficients.
+pub fn multiply_polynomials<F: FftField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let result_len = a.len() + b.len() - 1;
+    let size = smallest_supported_size::<F>(result_len)
+        .expect("Field must support a subgroup large enough for the product");
+    let plan = MixedRadixPlan::<F>::cached(size);
+    convolve(
+        a,
+        b,
+        size,
+        |values| plan.forward(values),
+        |values| plan.inverse(values),
+    )
+}
+
+/// Splits `size` into radix-4 stages, at most one radix-2 stage and the
+/// remaining odd prime factors.
+fn factorise(mut size: usize) -> Vec<usize> {
+    let mut radices = vec![];
+    while size.is_multiple_of(4) {
+        radices.push(4);
+        size /= 4;
+    }
+    if size.is_multiple_of(2) {
+        radices.push(2);
+        size /= 2;
+    }
+
+    let mut factor = 3;
+    while size > 1 {
+        while size.is_multiple_of(factor) {
+            radices.push(factor);
+            size /= factor;
+        }
+        factor += 2;
+    }
+    radices
+}
+
+/// In-place DFT of `values` where `roots[e] = ωᵉ` for ω of order `values.len()`.
+fn small_dft<F: FftField>(values: &mut [F], roots: &[F]) {
+    match values.len() {
+        2 => {
+            let (a, b) = (values[0], values[1]);
+            values[0] = a + b;
+            values[1] = a - b;
+        }
+        3 => {
+            let (a, b, c) = (values[0], values[1], values[2]);
+            let (w, w2) = (roots[1], roots[2]);
+            values[0] = a + b + c;
+            values[1] = a + b * w + c * w2;
+            values[2] = a + b * w2 + c * w;
+        }
+        4 => {
+            let (a, b, c, d) = (values[0], values[1], values[2], values[3]);
+            let (sum_ac, diff_ac) = (a + c, a - c);
+            let (sum_bd, diff_bd) = (b + d, (b - d) * roots[1]);
+            values[0] = sum_ac + sum_bd;
+            values[1] = diff_ac + diff_bd;
+            values[2] = sum_ac - sum_bd;
+            values[3] = diff_ac - diff_bd;
+        }
+        r => {
+            let input = values.to_vec();
+            for (s, value) in values.iter_mut().enumerate() {
+                *value = input
+                    .iter()
+                    .enumerate()
+                    .fold(F::zero(), |acc, (j, x)| acc + *x * roots[(j * s) % r]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::{Field, UniformRand};
+
+    fn naive_dft(coefficients: &[Fr]) -> Vec<Fr> {
+        let omega = Fr::get_root_of_unity(coefficients.len() as u64).unwrap();
+        (0..coefficients.len())
+            .map(|i| {
+                let x = omega.pow([i as u64]);
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(Fr::from(0), |acc, c| acc * x + c)
+            })
+            .collect()
+    }
+
+    fn naive_multiply(a: &[Fr], b: &[Fr]) -> Vec<Fr> {
+        let mut result = vec![Fr::from(0); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                result[i + j] += *x * y;
+            }
+        }
+        result
+    }
+
+    fn random_values(n: usize) -> Vec<Fr> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_factorise() {
+        assert_eq!(factorise(48), vec![4, 4, 3]);
+        assert_eq!(factorise(72), vec![4, 2, 3, 3]);
+        assert_eq!(factorise(9), vec![3, 3]);
+        assert_eq!(factorise(1), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_forward_matches_naive_dft() {
+        for size in [1, 2, 3, 4, 6, 8, 9, 12, 18, 24, 36, 48, 72] {
+            let coefficients = random_values(size);
+            let mut values = coefficients.clone();
+            MixedRadixPlan::new(size).forward(&mut values);
+            assert_eq!(values, naive_dft(&coefficients), "size {}", size);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for size in [3, 12, 96, 288] {
+            let coefficients = random_values(size);
+            let plan = MixedRadixPlan::<Fr>::cached(size);
+            let mut values = coefficients.clone();
+            plan.forward(&mut values);
+            plan.inverse(&mut values);
+            assert_eq!(values, coefficients, "size {}", size);
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_radix_2_plan() {
+        let coefficients = random_values(64);
+        let mut mixed = coefficients.clone();
+        MixedRadixPlan::<Fr>::new(64).forward(&mut mixed);
+        let mut radix_2 = coefficients.clone();
+        crate::ntt::NttPlan::<Fr>::new(64).forward(&mut radix_2);
+        assert_eq!(mixed, radix_2);
+    }
+
+    #[test]
+    fn test_smallest_supported_size() {
+        // BN254 Fr has 2-adicity 28 and a subgroup of order 3²
+        assert_eq!(smallest_supported_size::<Fr>(1), Some(1));
+        assert_eq!(smallest_supported_size::<Fr>(5), Some(6));
+        assert_eq!(smallest_supported_size::<Fr>(65), Some(72));
+        assert_eq!(smallest_supported_size::<Fr>(97), Some(128));
+        assert_eq!(smallest_supported_size::<Fr>(1 << 30), Some(9 << 27));
+        assert_eq!(smallest_supported_size::<Fr>((9 << 28) + 1), None);
+    }
+
+    #[test]
+    fn test_multiply_polynomials() {
+        for (len_a, len_b) in [(1, 1), (3, 4), (17, 24), (40, 33)] {
+            let a = random_values(len_a);
+            let b = random_values(len_b);
+            assert_eq!(multiply_polynomials(&a, &b), naive_multiply(&a, &b));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "field has no multiplicative subgroup of size 27")]
+    fn test_unsupported_size() {
+        MixedRadixPlan::<Fr>::new(27);
+    }
+}
@@ -0,0 +1,460 @@
+use ark_ff::FftField;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Below this many elements the `parallel` feature keeps work on the calling
+/// thread, where splitting costs more than it saves.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_LEN: usize = 1 << 10;
+
+/// Below this many coefficients in the smaller factor, a schoolbook product
+/// beats the three transforms of [`multiply_polynomials`].
+///
+/// From `cargo bench -p univariate_poly --bench multiplication` over BN254
+/// `Fr` with equal-length factors: schoolbook wins at 40 coefficients (69 µs
+/// against 87 µs, as the product pads to 128 points), the two are level at
+/// 48 (85 µs against 80 µs), and the transform takes half the time at 64
+/// (85 µs against 156 µs). `univariate_poly` falls back to Karatsuba instead
+/// and has its own `KARATSUBA_FFT_MUL_THRESHOLD`.
+pub const FFT_MUL_THRESHOLD: usize = 64;
+
+thread_local! {
+    static PLAN_CACHE: RefCell<HashMap<(TypeId, usize), Arc<dyn Any + Send + Sync>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Twiddle factors for radix-2 NTTs of one power-of-two size.
+///
+/// `twiddles[k] = ωᵏ` for `k < n/2`, where ω is the primitive nth root of
+/// unity; a butterfly in the stage of width `m` uses `twiddles[j · n/m]`, so
+/// every transform of this size shares the same table.
+#[derive(Debug, Clone)]
+pub struct NttPlan<F: FftField> {
+    size: usize,
+    twiddles: Vec<F>,
+    inverse_twiddles: Vec<F>,
+    size_inv: F,
+}
+
+impl<F: FftField> NttPlan<F> {
+    pub fn new(size: usize) -> Self {
+        if size == 0 || !size.is_power_of_two() {
+            panic!("FFT input length must be a power of 2 and non-zero");
+        }
+
+        let omega = F::get_root_of_unity(size as u64)
+            .expect("Field must support nth root of unity for FFT");
+        let omega_inv = omega.inverse().expect("Root of unity must be invertible");
+
+        Self {
+            size,
+            twiddles: powers(omega, size / 2),
+            inverse_twiddles: powers(omega_inv, size / 2),
+            size_inv: F::from(size as u64).inverse().unwrap(),
+        }
+    }
+
+    /// Returns the plan for `size`, building it on first use in this thread.
+    pub fn cached(size: usize) -> Arc<Self> {
+        cached_plan(size, Self::new)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn twiddles(&self) -> &[F] {
+        &self.twiddles
+    }
+
+    pub fn inverse_twiddles(&self) -> &[F] {
+        &self.inverse_twiddles
+    }
+
+    /// Coefficients in natural order to evaluations in natural order.
+    pub fn forward(&self, values: &mut [F]) {
+        self.forward_dif(values);
+        bit_reverse_permutation(values);
+    }
+
+    /// Evaluations in natural order to coefficients in natural order.
+    pub fn inverse(&self, values: &mut [F]) {
+        bit_reverse_permutation(values);
+        self.inverse_dit(values);
+    }
+
+    /// Forward transform taking natural-order input to bit-reversed output.
+    pub fn forward_dif(&self, values: &mut [F]) {
+        self.check_len(values);
+        dif_in_place(values, &self.twiddles);
+    }
+
+    /// Forward transform taking bit-reversed input to natural-order output.
+    pub fn forward_dit(&self, values: &mut [F]) {
+        self.check_len(values);
+        dit_in_place(values, &self.twiddles);
+    }
+
+    /// Inverse transform taking natural-order input to bit-reversed output.
+    pub fn inverse_dif(&self, values: &mut [F]) {
+        self.check_len(values);
+        dif_in_place(values, &self.inverse_twiddles);
+        self.scale(values);
+    }
+
+    /// Inverse transform taking bit-reversed input to natural-order output.
+    pub fn inverse_dit(&self, values: &mut [F]) {
+        self.check_len(values);
+        dit_in_place(values, &self.inverse_twiddles);
+        self.scale(values);
+    }
+
+    fn scale(&self, values: &mut [F]) {
+        scale_in_place(values, self.size_inv);
+    }
+
+    fn check_len(&self, values: &[F]) {
+        assert_eq!(
+            values.len(),
+            self.size,
+            "input length must match the plan size"
+        );
+    }
+}
+
+/// Looks up the plan of type `P` for `size` in this thread's cache, building
+/// it with `build` on first use.
+pub(crate) fn cached_plan<P: Any + Send + Sync>(size: usize, build: fn(usize) -> P) -> Arc<P> {
+    let key = (TypeId::of::<P>(), size);
+    if let Some(plan) = PLAN_CACHE.with(|cache| cache.borrow().get(&key).cloned()) {
+        return plan.downcast::<P>().expect("plan cache keyed by plan type");
+    }
+
+    let plan = Arc::new(build(size));
+    PLAN_CACHE.with(|cache| cache.borrow_mut().insert(key, plan.clone()));
+    plan
+}
+
+/// Decimation-in-time butterflies (Cooley–Tukey): bit-reversed input, natural output.
+///
+/// `twiddles` holds `ωᵏ` for `k < n/2` where ω has order `n = values.len()`.
+pub fn dit_in_place<F: FftField>(values: &mut [F], twiddles: &[F]) {
+    let n = values.len();
+    let mut half = 1;
+    while half < n {
+        let stride = n / (2 * half);
+        butterfly_stage(values, half, |j, u, v| {
+            let t = *v * twiddles[j * stride];
+            *v = *u - t;
+            *u += t;
+        });
+        half *= 2;
+    }
+}
+
+/// Decimation-in-frequency butterflies (Gentleman–Sande): natural input, bit-reversed output.
+///
+/// `twiddles` holds `ωᵏ` for `k < n/2` where ω has order `n = values.len()`.
+pub fn dif_in_place<F: FftField>(values: &mut [F], twiddles: &[F]) {
+    let n = values.len();
+    let mut half = n / 2;
+    while half > 0 {
+        let stride = n / (2 * half);
+        butterfly_stage(values, half, |j, u, v| {
+            let t = *u - *v;
+            *u += *v;
+            *v = t * twiddles[j * stride];
+        });
+        half /= 2;
+    }
+}
+
+/// Applies `butterfly(j, u, v)` to every pair `(chunk[j], chunk[j + half])`
+/// of every chunk of width `2·half`.
+///
+/// With the `parallel` feature, early stages split across chunks and late
+/// stages, which have few wide chunks, split within each chunk instead.
+/// Every butterfly sees the same inputs as on the serial path, so the
+/// output is identical.
+fn butterfly_stage<F, B>(values: &mut [F], half: usize, butterfly: B)
+where
+    F: FftField,
+    B: Fn(usize, &mut F, &mut F) + Sync,
+{
+    let apply = |chunk: &mut [F]| {
+        let (lo, hi) = chunk.split_at_mut(half);
+        for (j, (u, v)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+            butterfly(j, u, v);
+        }
+    };
+
+    #[cfg(feature = "parallel")]
+    if values.len() >= MIN_PARALLEL_LEN {
+        if half < MIN_PARALLEL_LEN {
+            values.par_chunks_mut(2 * half).for_each(apply);
+        } else {
+            for chunk in values.chunks_mut(2 * half) {
+                let (lo, hi) = chunk.split_at_mut(half);
+                lo.par_iter_mut()
+                    .zip(hi.par_iter_mut())
+                    .enumerate()
+                    .for_each(|(j, (u, v))| butterfly(j, u, v));
+            }
+        }
+        return;
+    }
+
+    values.chunks_mut(2 * half).for_each(apply);
+}
+
+/// `values[i] *= factor` for every `i`.
+pub(crate) fn scale_in_place<F: FftField>(values: &mut [F], factor: F) {
+    #[cfg(feature = "parallel")]
+    if values.len() >= MIN_PARALLEL_LEN {
+        values.par_iter_mut().for_each(|value| *value *= factor);
+        return;
+    }
+
+    values.iter_mut().for_each(|value| *value *= factor);
+}
+
+/// `values[i] *= other[i]` for every `i`.
+pub(crate) fn mul_assign_pointwise<F: FftField>(values: &mut [F], other: &[F]) {
+    #[cfg(feature = "parallel")]
+    if values.len() >= MIN_PARALLEL_LEN {
+        values
+            .par_iter_mut()
+            .zip(other.par_iter())
+            .for_each(|(x, y)| *x *= y);
+        return;
+    }
+
+    for (x, y) in values.iter_mut().zip(other.iter()) {
+        *x *= y;
+    }
+}
+
+/// Product of two coefficient vectors over the smallest power-of-two
+/// subgroup that holds it, of length `a.len() + b.len() - 1`.
+///
+/// Panics if the field has no subgroup of that size.
+pub fn multiply_polynomials<F: FftField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let plan = NttPlan::<F>::cached((a.len() + b.len() - 1).next_power_of_two());
+    // both operands stay in bit-reversed order between the transforms
+    convolve(
+        a,
+        b,
+        plan.size(),
+        |values| plan.forward_dif(values),
+        |values| plan.inverse_dit(values),
+    )
+}
+
+/// Pads `a` and `b` to `size`, multiplies them pointwise between `forward`
+/// and `inverse`, and returns the `a.len() + b.len() - 1` product coefficients.
+pub(crate) fn convolve<F: FftField>(
+    a: &[F],
+    b: &[F],
+    size: usize,
+    forward: impl Fn(&mut [F]),
+    inverse: impl Fn(&mut [F]),
+) -> Vec<F> {
+    let result_len = a.len() + b.len() - 1;
+    let mut evals_a = a.to_vec();
+    let mut evals_b = b.to_vec();
+    evals_a.resize(size, F::zero());
+    evals_b.resize(size, F::zero());
+
+    forward(&mut evals_a);
+    forward(&mut evals_b);
+    mul_assign_pointwise(&mut evals_a, &evals_b);
+    inverse(&mut evals_a);
+
+    evals_a.truncate(result_len);
+    evals_a
+}
+
+/// Reorders `values` so that index `i` moves to the bit reversal of `i`.
+pub fn bit_reverse_permutation<T>(values: &mut [T]) {
+    let n = values.len();
+    if n <= 2 {
+        return;
+    }
+    assert!(n.is_power_of_two(), "length must be a power of 2");
+
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+/// `[1, base, base², ..., base^(count-1)]`.
+///
+/// With the `parallel` feature each block starts from `base^start` and runs
+/// the same sequential recurrence, so the table matches the serial one.
+pub(crate) fn powers<F: FftField>(base: F, count: usize) -> Vec<F> {
+    let mut result = vec![F::zero(); count];
+    let fill = |start: usize, block: &mut [F]| {
+        let mut current = base.pow([start as u64]);
+        for value in block.iter_mut() {
+            *value = current;
+            current *= base;
+        }
+    };
+
+    #[cfg(feature = "parallel")]
+    if count >= MIN_PARALLEL_LEN {
+        result
+            .par_chunks_mut(MIN_PARALLEL_LEN)
+            .enumerate()
+            .for_each(|(i, block)| fill(i * MIN_PARALLEL_LEN, block));
+        return result;
+    }
+
+    fill(0, &mut result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_377::Fr;
+    use ark_ff::{Field, UniformRand};
+
+    fn naive_dft(coefficients: &[Fr]) -> Vec<Fr> {
+        let omega = Fr::get_root_of_unity(coefficients.len() as u64).unwrap();
+        (0..coefficients.len())
+            .map(|i| {
+                let x = omega.pow([i as u64]);
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(Fr::from(0), |acc, c| acc * x + c)
+            })
+            .collect()
+    }
+
+    fn random_values(n: usize) -> Vec<Fr> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_bit_reverse_permutation() {
+        let mut values: Vec<usize> = (0..8).collect();
+        bit_reverse_permutation(&mut values);
+        assert_eq!(values, vec![0, 4, 2, 6, 1, 5, 3, 7]);
+    }
+
+    #[test]
+    fn test_forward_matches_naive_dft() {
+        for log_n in 0..7 {
+            let coefficients = random_values(1 << log_n);
+            let mut values = coefficients.clone();
+            NttPlan::new(1 << log_n).forward(&mut values);
+            assert_eq!(values, naive_dft(&coefficients));
+        }
+    }
+
+    #[test]
+    fn test_dit_and_dif_agree() {
+        let coefficients = random_values(32);
+        let plan = NttPlan::<Fr>::new(32);
+
+        let mut dif = coefficients.clone();
+        plan.forward_dif(&mut dif);
+        bit_reverse_permutation(&mut dif);
+
+        let mut dit = coefficients.clone();
+        bit_reverse_permutation(&mut dit);
+        plan.forward_dit(&mut dit);
+
+        assert_eq!(dif, dit);
+    }
+
+    #[test]
+    fn test_round_trip_without_permutation() {
+        let coefficients = random_values(64);
+        let plan = NttPlan::<Fr>::new(64);
+
+        let mut values = coefficients.clone();
+        plan.forward_dif(&mut values);
+        plan.inverse_dit(&mut values);
+        assert_eq!(values, coefficients);
+
+        let mut values = coefficients.clone();
+        bit_reverse_permutation(&mut values);
+        plan.inverse_dif(&mut values);
+        plan.forward_dit(&mut values);
+        bit_reverse_permutation(&mut values);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let coefficients = random_values(16);
+        let plan = NttPlan::<Fr>::new(16);
+        let mut values = coefficients.clone();
+        plan.forward(&mut values);
+        plan.inverse(&mut values);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn test_cached_plan_is_shared() {
+        let first = NttPlan::<Fr>::cached(128);
+        let second = NttPlan::<Fr>::cached(128);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.size(), 128);
+        assert_eq!(first.twiddles().len(), 64);
+        assert_eq!(
+            first.twiddles()[1] * first.inverse_twiddles()[1],
+            Fr::from(1)
+        );
+    }
+
+    #[test]
+    fn test_large_transform_matches_mixed_radix() {
+        // large enough for the `parallel` feature to split both across and
+        // within chunks
+        let coefficients = random_values(1 << 12);
+        let mut values = coefficients.clone();
+        NttPlan::<Fr>::new(1 << 12).forward(&mut values);
+
+        let mut expected = coefficients.clone();
+        crate::mixed_radix::MixedRadixPlan::<Fr>::new(1 << 12).forward(&mut expected);
+        assert_eq!(values, expected);
+
+        NttPlan::<Fr>::new(1 << 12).inverse(&mut values);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn test_powers() {
+        let base = Fr::from(7);
+        let table = powers(base, 3000);
+        let mut current = Fr::from(1);
+        for value in table {
+            assert_eq!(value, current);
+            current *= base;
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "FFT input length must be a power of 2 and non-zero")]
+    fn test_non_power_of_two() {
+        NttPlan::<Fr>::new(12);
+    }
+}
@@ -0,0 +1,357 @@
+use crate::division::multiply;
+use crate::domain::EvaluationDomain;
+use ark_ff::{BigInteger, FftField, PrimeField, batch_inversion};
+use std::fmt;
+
+/// Why [`ReedSolomon::decode`] or [`field_elements_to_bytes`] rejected
+/// their input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Fewer evaluations survived than the code's data length.
+    NotEnoughEvaluations { known: usize, needed: usize },
+    /// The surviving evaluations do not all lie on one codeword.
+    Inconsistent,
+    /// The length element is missing, not a valid length, or longer than
+    /// the chunks that follow it.
+    InvalidLength,
+    /// The chunk at this index does not fit in [`bytes_per_element`] bytes.
+    ChunkOutOfRange(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotEnoughEvaluations { known, needed } => write!(
+                f,
+                "need at least {needed} evaluations to recover the data, got {known}"
+            ),
+            Self::Inconsistent => f.write_str("evaluations are not consistent with any codeword"),
+            Self::InvalidLength => f.write_str("invalid length element"),
+            Self::ChunkOutOfRange(index) => write!(f, "chunk {index} does not fit its byte width"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Reed–Solomon code of `data_len` field elements over a roots-of-unity
+/// domain of `codeword_len` points.
+///
+/// The data are the coefficients of a polynomial `D` of degree below
+/// `data_len`, and the codeword is `D` evaluated over the domain, so any
+/// `data_len` surviving evaluations determine the data.
+#[derive(Debug, Clone)]
+pub struct ReedSolomon<F: FftField> {
+    data_len: usize,
+    domain: EvaluationDomain<F>,
+}
+
+impl<F: FftField> ReedSolomon<F> {
+    pub fn new(data_len: usize, codeword_len: usize) -> Self {
+        assert!(data_len > 0, "data length must be non-zero");
+        assert!(
+            codeword_len > data_len,
+            "codeword must be longer than the data"
+        );
+
+        let domain = EvaluationDomain::new(codeword_len);
+        assert_eq!(
+            domain.size(),
+            codeword_len,
+            "codeword length must be a power of 2"
+        );
+        Self { data_len, domain }
+    }
+
+    pub fn data_len(&self) -> usize {
+        self.data_len
+    }
+
+    pub fn codeword_len(&self) -> usize {
+        self.domain.size()
+    }
+
+    pub fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Evaluates the data polynomial over the domain. Data shorter than
+    /// `data_len` is padded with zeros.
+    pub fn encode(&self, data: &[F]) -> Vec<F> {
+        assert!(
+            data.len() <= self.data_len,
+            "data is longer than the code's data length"
+        );
+        self.domain.fft(data)
+    }
+
+    /// Recovers the data from a codeword with erasures, `None` marking a
+    /// lost evaluation.
+    ///
+    /// With `Z` vanishing on the erased points and `E` the codeword with
+    /// zeros at those points, `E·Z = D·Z` holds on the whole domain, so one
+    /// inverse FFT gives `D·Z` and `D` is its quotient by `Z`, taken on a
+    /// coset where `Z` has no roots.
+    ///
+    /// Fails if fewer than `data_len` evaluations are known, or if the known
+    /// ones are not all on one codeword.
+    pub fn decode(&self, codeword: &[Option<F>]) -> Result<Vec<F>, DecodeError> {
+        let n = self.codeword_len();
+        assert_eq!(
+            codeword.len(),
+            n,
+            "expected one entry per codeword position"
+        );
+
+        let erased: Vec<usize> = (0..n).filter(|&i| codeword[i].is_none()).collect();
+        let known = n - erased.len();
+        if known < self.data_len {
+            return Err(DecodeError::NotEnoughEvaluations {
+                known,
+                needed: self.data_len,
+            });
+        }
+        if erased.is_empty() {
+            let evaluations: Vec<F> = codeword.iter().map(|value| value.unwrap()).collect();
+            return self.truncate_data(self.domain.ifft(&evaluations));
+        }
+
+        let erased_points: Vec<F> = erased.iter().map(|&i| self.domain.element(i)).collect();
+        let vanishing = vanishing_polynomial(&erased_points);
+        let vanishing_evals = self.domain.fft(&vanishing);
+
+        let product_evals: Vec<F> = codeword
+            .iter()
+            .zip(vanishing_evals.iter())
+            .map(|(value, z)| value.map_or(F::zero(), |value| value * z))
+            .collect();
+        let product = self.domain.ifft(&product_evals);
+
+        let coset = self.domain.get_coset(F::GENERATOR);
+        let mut quotient_evals = coset.fft(&product);
+        let mut vanishing_on_coset = coset.fft(&vanishing);
+        batch_inversion(&mut vanishing_on_coset);
+        for (value, z_inv) in quotient_evals.iter_mut().zip(vanishing_on_coset.iter()) {
+            *value *= z_inv;
+        }
+
+        self.truncate_data(coset.ifft(&quotient_evals))
+    }
+
+    fn truncate_data(&self, mut coefficients: Vec<F>) -> Result<Vec<F>, DecodeError> {
+        if !coefficients[self.data_len..].iter().all(|c| c.is_zero()) {
+            return Err(DecodeError::Inconsistent);
+        }
+        coefficients.truncate(self.data_len);
+        Ok(coefficients)
+    }
+}
+
+/// Number of bytes packed into each field element, so that every chunk is
+/// below the modulus.
+pub fn bytes_per_element<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize - 1) / 8
+}
+
+/// Packs a byte blob into field elements: the first element holds the blob
+/// length, the rest hold little-endian chunks of [`bytes_per_element`] bytes.
+pub fn bytes_to_field_elements<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    let chunk_len = bytes_per_element::<F>();
+    assert!(chunk_len > 0, "field is too small to pack bytes into");
+
+    let mut elements = vec![F::from(bytes.len() as u64)];
+    elements.extend(bytes.chunks(chunk_len).map(F::from_le_bytes_mod_order));
+    elements
+}
+
+/// Inverse of [`bytes_to_field_elements`]. Trailing elements beyond the
+/// recorded length, such as erasure-coding padding, are ignored, but every
+/// element must still be a chunk [`bytes_to_field_elements`] could produce.
+pub fn field_elements_to_bytes<F: PrimeField>(elements: &[F]) -> Result<Vec<u8>, DecodeError> {
+    let chunk_len = bytes_per_element::<F>();
+    let (length, chunks) = elements.split_first().ok_or(DecodeError::InvalidLength)?;
+    let length_limbs = length.into_bigint();
+    if length_limbs.as_ref()[1..].iter().any(|&limb| limb != 0) {
+        return Err(DecodeError::InvalidLength);
+    }
+    let length =
+        usize::try_from(length_limbs.as_ref()[0]).map_err(|_| DecodeError::InvalidLength)?;
+    if chunks.len().saturating_mul(chunk_len) < length {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let mut bytes = Vec::with_capacity(length);
+    for (index, chunk) in chunks.iter().enumerate() {
+        let chunk_bytes = chunk.into_bigint().to_bytes_le();
+        if chunk_bytes[chunk_len..].iter().any(|&byte| byte != 0) {
+            return Err(DecodeError::ChunkOutOfRange(index));
+        }
+        bytes.extend_from_slice(&chunk_bytes[..chunk_len]);
+    }
+    bytes.truncate(length);
+    Ok(bytes)
+}
+
+/// `Π (x - pᵢ)` over `points`, built as a balanced product tree so large
+/// erasure sets use FFT multiplication.
+fn vanishing_polynomial<F: FftField>(points: &[F]) -> Vec<F> {
+    let mut layer: Vec<Vec<F>> = points.iter().map(|&point| vec![-point, F::one()]).collect();
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => multiply(a, b),
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    layer.pop().unwrap_or_else(|| vec![F::one()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_377::Fr;
+    use ark_ff::{Field, UniformRand};
+
+    fn random_values(n: usize) -> Vec<Fr> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    fn erase(codeword: &[Fr], erased: &[usize]) -> Vec<Option<Fr>> {
+        codeword
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| (!erased.contains(&i)).then_some(value))
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_is_polynomial_evaluation() {
+        let code = ReedSolomon::<Fr>::new(3, 8);
+        let data = vec![Fr::from(1), Fr::from(2), Fr::from(3)];
+        let codeword = code.encode(&data);
+
+        for (i, value) in codeword.iter().enumerate() {
+            let x = code.domain().element(i);
+            assert_eq!(*value, data[0] + data[1] * x + data[2] * x * x);
+        }
+    }
+
+    #[test]
+    fn test_decode_without_erasures() {
+        let code = ReedSolomon::<Fr>::new(5, 16);
+        let data = random_values(5);
+        let codeword = code.encode(&data);
+        assert_eq!(code.decode(&erase(&codeword, &[])), Ok(data));
+    }
+
+    #[test]
+    fn test_decode_from_any_k_evaluations() {
+        let code = ReedSolomon::<Fr>::new(4, 8);
+        let data = random_values(4);
+        let codeword = code.encode(&data);
+
+        for erased in [
+            vec![0, 1, 2, 3],
+            vec![4, 5, 6, 7],
+            vec![0, 2, 5, 7],
+            vec![1, 6],
+        ] {
+            assert_eq!(code.decode(&erase(&codeword, &erased)), Ok(data.clone()));
+        }
+    }
+
+    #[test]
+    fn test_decode_large_erasure_set() {
+        let code = ReedSolomon::<Fr>::new(100, 256);
+        let data = random_values(100);
+        let codeword = code.encode(&data);
+        let erased: Vec<usize> = (0..256).filter(|i| i % 3 != 0).take(156).collect();
+        assert_eq!(code.decode(&erase(&codeword, &erased)), Ok(data));
+    }
+
+    #[test]
+    fn test_decode_too_many_erasures() {
+        let code = ReedSolomon::<Fr>::new(4, 8);
+        let codeword = code.encode(&random_values(4));
+        assert_eq!(
+            code.decode(&erase(&codeword, &[0, 1, 2, 3, 4])),
+            Err(DecodeError::NotEnoughEvaluations {
+                known: 3,
+                needed: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_detects_corruption() {
+        let code = ReedSolomon::<Fr>::new(4, 8);
+        let mut codeword = code.encode(&random_values(4));
+        codeword[7] += Fr::from(1);
+        assert_eq!(
+            code.decode(&erase(&codeword, &[0, 1])),
+            Err(DecodeError::Inconsistent)
+        );
+        assert_eq!(
+            code.decode(&erase(&codeword, &[])),
+            Err(DecodeError::Inconsistent)
+        );
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        for len in [0usize, 1, 30, 31, 32, 100] {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+            let elements = bytes_to_field_elements::<Fr>(&bytes);
+            assert_eq!(elements.len(), 1 + len.div_ceil(bytes_per_element::<Fr>()));
+            assert_eq!(field_elements_to_bytes(&elements), Ok(bytes));
+        }
+    }
+
+    #[test]
+    fn test_bytes_through_erasure_code() {
+        let blob: Vec<u8> = (0..=255).collect();
+        let data = bytes_to_field_elements::<Fr>(&blob);
+        let code = ReedSolomon::<Fr>::new(data.len(), 4 * data.len().next_power_of_two());
+        let codeword = code.encode(&data);
+
+        let erased: Vec<usize> = (0..code.codeword_len() - data.len()).collect();
+        let recovered = code.decode(&erase(&codeword, &erased)).unwrap();
+        assert_eq!(field_elements_to_bytes(&recovered), Ok(blob));
+    }
+
+    #[test]
+    fn test_malformed_bytes_are_rejected() {
+        let chunk_len = bytes_per_element::<Fr>();
+        let elements = bytes_to_field_elements::<Fr>(&[7; 40]);
+
+        // a chunk with bits above its byte width would otherwise be cut off
+        let mut oversized = elements.clone();
+        oversized[2] += Fr::from(2u64).pow([8 * chunk_len as u64]);
+        assert_eq!(
+            field_elements_to_bytes(&oversized),
+            Err(DecodeError::ChunkOutOfRange(1))
+        );
+
+        let mut too_long = elements.clone();
+        too_long[0] = Fr::from(2 * chunk_len as u64 + 1);
+        assert_eq!(
+            field_elements_to_bytes(&too_long),
+            Err(DecodeError::InvalidLength)
+        );
+
+        let mut huge_length = elements;
+        huge_length[0] = -Fr::from(1);
+        assert_eq!(
+            field_elements_to_bytes(&huge_length),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(
+            field_elements_to_bytes::<Fr>(&[]),
+            Err(DecodeError::InvalidLength)
+        );
+    }
+}
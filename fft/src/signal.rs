@@ -0,0 +1,420 @@
+use num_complex::Complex64;
+use rustfft::{Fft, FftPlanner};
+use std::cell::RefCell;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+thread_local! {
+    static PLANNER: RefCell<FftPlanner<f64>> = RefCell::new(FftPlanner::new());
+}
+
+/// Unit roundoff of `f64`, `2⁻⁵³`.
+const UNIT_ROUNDOFF: f64 = f64::EPSILON / 2.0;
+
+/// Assumed bound on the error of each twiddle factor. rustfft computes
+/// `cos`/`sin` of `-2πk/n` directly, which is off by a few ulps of the angle;
+/// `2⁻⁴⁸` leaves a wide margin over that.
+const TWIDDLE_ERROR: f64 = 1.0 / (1u64 << 48) as f64;
+
+/// Largest magnitude an `i64` can have and still convert to `f64` exactly.
+const MAX_EXACT_INTEGER: u64 = 1 << 53;
+
+/// Window functions applied before a transform to reduce spectral leakage.
+/// All are the symmetric variants, so `coefficients(n)` starts and ends on
+/// the same value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl Window {
+    pub fn coefficients(&self, n: usize) -> Vec<f64> {
+        match n {
+            0 => return vec![],
+            1 => return vec![1.0],
+            _ => {}
+        }
+
+        let denom = (n - 1) as f64;
+        (0..n)
+            .map(|i| {
+                let phase = 2.0 * PI * i as f64 / denom;
+                match self {
+                    Window::Rectangular => 1.0,
+                    Window::Hann => 0.5 - 0.5 * phase.cos(),
+                    Window::Hamming => 0.54 - 0.46 * phase.cos(),
+                    Window::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+                }
+            })
+            .collect()
+    }
+
+    /// Multiplies `signal` by the window in place.
+    pub fn apply(&self, signal: &mut [f64]) {
+        let weights = self.coefficients(signal.len());
+        for (sample, weight) in signal.iter_mut().zip(weights) {
+            *sample *= weight;
+        }
+    }
+}
+
+/// Forward FFT of a real signal, returning the `n/2 + 1` non-redundant bins
+/// `X[k] = Σ x[j]·e^(-2πijk/n)`; the rest follow from `X[n-k] = conj(X[k])`.
+///
+/// Even lengths pack the signal into a complex one of half the length,
+/// `z[j] = x[2j] + i·x[2j+1]`, and unpack its spectrum, halving the work.
+pub fn rfft(signal: &[f64]) -> Vec<Complex64> {
+    let n = signal.len();
+    if n == 0 {
+        return vec![];
+    }
+    if n % 2 == 1 {
+        let mut buffer: Vec<Complex64> = signal.iter().map(|&x| Complex64::new(x, 0.0)).collect();
+        forward_plan(n).process(&mut buffer);
+        buffer.truncate(n / 2 + 1);
+        return buffer;
+    }
+
+    let m = n / 2;
+    let mut packed: Vec<Complex64> = signal
+        .chunks(2)
+        .map(|pair| Complex64::new(pair[0], pair[1]))
+        .collect();
+    forward_plan(m).process(&mut packed);
+
+    // E[k] = (Z[k] + conj(Z[m-k])) / 2, O[k] = (Z[k] - conj(Z[m-k])) / 2i,
+    // X[k] = E[k] + e^(-2πik/n)·O[k]
+    (0..=m)
+        .map(|k| {
+            let z_k = packed[k % m];
+            let z_mirror = packed[(m - k) % m].conj();
+            let even = (z_k + z_mirror) * 0.5;
+            let odd = (z_k - z_mirror) * Complex64::new(0.0, -0.5);
+            even + twiddle(k, n) * odd
+        })
+        .collect()
+}
+
+/// Inverse of [`rfft`]: rebuilds the real signal of length `n` from its
+/// `n/2 + 1` non-redundant bins, including the `1/n` normalisation.
+pub fn irfft(spectrum: &[Complex64], n: usize) -> Vec<f64> {
+    if n == 0 {
+        return vec![];
+    }
+    assert_eq!(spectrum.len(), n / 2 + 1, "spectrum must hold n/2 + 1 bins");
+
+    if n % 2 == 1 {
+        let mut buffer: Vec<Complex64> = (0..n)
+            .map(|k| {
+                if k < spectrum.len() {
+                    spectrum[k]
+                } else {
+                    spectrum[n - k].conj()
+                }
+            })
+            .collect();
+        inverse_plan(n).process(&mut buffer);
+        return buffer.iter().map(|c| c.re / n as f64).collect();
+    }
+
+    // Undo the unpacking of `rfft`:
+    // E[k] = (X[k] + conj(X[m-k])) / 2, O[k] = (X[k] - conj(X[m-k])) / 2·e^(2πik/n)
+    let m = n / 2;
+    let mut packed: Vec<Complex64> = (0..m)
+        .map(|k| {
+            let x_k = spectrum[k];
+            let x_mirror = spectrum[m - k].conj();
+            let even = (x_k + x_mirror) * 0.5;
+            let odd = (x_k - x_mirror) * 0.5 * twiddle(k, n).conj();
+            even + Complex64::i() * odd
+        })
+        .collect();
+    inverse_plan(m).process(&mut packed);
+
+    packed
+        .iter()
+        .flat_map(|z| [z.re / m as f64, z.im / m as f64])
+        .collect()
+}
+
+/// Linear convolution `c[k] = Σ a[i]·b[k-i]`, of length `a.len() + b.len() - 1`.
+pub fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let result_len = a.len() + b.len() - 1;
+    let size = result_len.next_power_of_two();
+
+    let mut padded_a = a.to_vec();
+    let mut padded_b = b.to_vec();
+    padded_a.resize(size, 0.0);
+    padded_b.resize(size, 0.0);
+
+    let spectrum: Vec<Complex64> = rfft(&padded_a)
+        .into_iter()
+        .zip(rfft(&padded_b))
+        .map(|(x, y)| x * y)
+        .collect();
+
+    let mut result = irfft(&spectrum, size);
+    result.truncate(result_len);
+    result
+}
+
+/// Full cross-correlation `r[lag] = Σ a[i + lag]·b[i]` for lags from
+/// `-(b.len() - 1)` to `a.len() - 1`; `r[lag]` is at index `lag + b.len() - 1`.
+pub fn cross_correlate(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let reversed: Vec<f64> = b.iter().rev().copied().collect();
+    convolve(a, &reversed)
+}
+
+/// Upper bound on the largest coefficient error of [`multiply_integer_polynomials`]
+/// before rounding, following Percival's analysis of FFT convolution:
+///
+/// `‖a‖₂·‖b‖₂·((1+ε)^(3L)·(1+ε√5)^(3L+1)·(1+β)^(3L) - 1)`
+///
+/// with `L = log₂` of the transform size, `ε` the unit roundoff and `β` the
+/// twiddle error. Rounding recovers the exact product whenever this is below ½.
+pub fn convolution_error_bound(a: &[f64], b: &[f64]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let size = (a.len() + b.len() - 1).next_power_of_two();
+    let levels = size.trailing_zeros() as f64;
+    let norm = |values: &[f64]| values.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    // (1+x)^k - 1 through ln_1p/exp_m1, since 1 + ε rounds back to 1
+    let log_growth = 3.0 * levels * UNIT_ROUNDOFF.ln_1p()
+        + (3.0 * levels + 1.0) * (UNIT_ROUNDOFF * 5f64.sqrt()).ln_1p()
+        + 3.0 * levels * TWIDDLE_ERROR.ln_1p();
+    norm(a) * norm(b) * log_growth.exp_m1()
+}
+
+/// Multiplies integer polynomials with floating-point FFTs, rounding each
+/// coefficient of the product back to an integer.
+///
+/// Panics if [`convolution_error_bound`] cannot guarantee that rounding is
+/// exact, rather than returning a silently wrong product.
+pub fn multiply_integer_polynomials(a: &[i64], b: &[i64]) -> Vec<i64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    assert!(
+        a.iter()
+            .chain(b.iter())
+            .all(|x| x.unsigned_abs() <= MAX_EXACT_INTEGER),
+        "coefficients must fit in the f64 mantissa"
+    );
+
+    let a: Vec<f64> = a.iter().map(|&x| x as f64).collect();
+    let b: Vec<f64> = b.iter().map(|&x| x as f64).collect();
+    assert!(
+        convolution_error_bound(&a, &b) < 0.5,
+        "coefficients too large for an exact f64 convolution"
+    );
+
+    let result_len = a.len() + b.len() - 1;
+    let size = result_len.next_power_of_two();
+    let to_buffer = |values: &[f64]| {
+        let mut buffer: Vec<Complex64> = values.iter().map(|&x| Complex64::new(x, 0.0)).collect();
+        buffer.resize(size, Complex64::new(0.0, 0.0));
+        forward_plan(size).process(&mut buffer);
+        buffer
+    };
+
+    // Full complex transforms, the setting the error bound is stated for
+    let mut product = to_buffer(&a);
+    for (x, y) in product.iter_mut().zip(to_buffer(&b)) {
+        *x *= y;
+    }
+    inverse_plan(size).process(&mut product);
+
+    product
+        .iter()
+        .take(result_len)
+        .map(|c| (c.re / size as f64).round() as i64)
+        .collect()
+}
+
+/// `e^(-2πik/n)`, the forward-transform twiddle.
+fn twiddle(k: usize, n: usize) -> Complex64 {
+    Complex64::from_polar(1.0, -2.0 * PI * k as f64 / n as f64)
+}
+
+fn forward_plan(n: usize) -> Arc<dyn Fft<f64>> {
+    PLANNER.with(|planner| planner.borrow_mut().plan_fft_forward(n))
+}
+
+fn inverse_plan(n: usize) -> Arc<dyn Fft<f64>> {
+    PLANNER.with(|planner| planner.borrow_mut().plan_fft_inverse(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_377::Fr;
+    use ark_std::rand::Rng;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn naive_dft(signal: &[f64]) -> Vec<Complex64> {
+        let n = signal.len();
+        (0..n)
+            .map(|k| {
+                signal
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &x)| x * twiddle(j * k % n, n))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn naive_convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                result[i + j] += x * y;
+            }
+        }
+        result
+    }
+
+    fn random_signal(n: usize) -> Vec<f64> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect()
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (x, y) in actual.iter().zip(expected.iter()) {
+            assert!((x - y).abs() < TOLERANCE, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn test_rfft_matches_naive_dft() {
+        for n in [1, 2, 3, 8, 15, 64, 100] {
+            let signal = random_signal(n);
+            let expected = naive_dft(&signal);
+            let actual = rfft(&signal);
+
+            assert_eq!(actual.len(), n / 2 + 1);
+            for (x, y) in actual.iter().zip(expected.iter()) {
+                assert!((x - y).norm() < TOLERANCE, "n = {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_irfft_round_trip() {
+        for n in [1, 2, 7, 16, 90] {
+            let signal = random_signal(n);
+            assert_close(&irfft(&rfft(&signal), n), &signal);
+        }
+    }
+
+    #[test]
+    fn test_convolve() {
+        assert_close(
+            &convolve(&[1.0, 2.0, 3.0], &[4.0, 5.0]),
+            &[4.0, 13.0, 22.0, 15.0],
+        );
+
+        let a = random_signal(37);
+        let b = random_signal(20);
+        assert_close(&convolve(&a, &b), &naive_convolve(&a, &b));
+    }
+
+    #[test]
+    fn test_cross_correlate() {
+        // lags -1, 0, 1, 2 of [1, 2, 3] against [1, 1]
+        assert_close(
+            &cross_correlate(&[1.0, 2.0, 3.0], &[1.0, 1.0]),
+            &[1.0, 3.0, 5.0, 3.0],
+        );
+
+        // a shifted copy peaks at its shift
+        let b = random_signal(16);
+        let mut a = vec![0.0; 5];
+        a.extend(&b);
+        let correlation = cross_correlate(&a, &b);
+        let peak = (0..correlation.len())
+            .max_by(|&i, &j| correlation[i].total_cmp(&correlation[j]))
+            .unwrap();
+        assert_eq!(peak as i64 - (b.len() as i64 - 1), 5);
+    }
+
+    #[test]
+    fn test_windows() {
+        let hann = Window::Hann.coefficients(5);
+        assert_close(&hann, &[0.0, 0.5, 1.0, 0.5, 0.0]);
+
+        let hamming = Window::Hamming.coefficients(3);
+        assert_close(&hamming, &[0.08, 1.0, 0.08]);
+
+        let blackman = Window::Blackman.coefficients(3);
+        assert_close(&blackman, &[0.0, 1.0, 0.0]);
+
+        let mut signal = vec![2.0; 4];
+        Window::Rectangular.apply(&mut signal);
+        assert_eq!(signal, vec![2.0; 4]);
+    }
+
+    #[test]
+    fn test_empty_window() {
+        for window in [
+            Window::Rectangular,
+            Window::Hann,
+            Window::Hamming,
+            Window::Blackman,
+        ] {
+            assert!(window.coefficients(0).is_empty());
+            window.apply(&mut []);
+        }
+    }
+
+    #[test]
+    fn test_multiply_integer_polynomials() {
+        assert_eq!(
+            multiply_integer_polynomials(&[1, 2, 3], &[4, 5]),
+            vec![4, 13, 22, 15]
+        );
+        assert_eq!(
+            multiply_integer_polynomials(&[-6, -1, 2], &[1, -1]),
+            vec![-6, 5, 3, -2]
+        );
+    }
+
+    #[test]
+    fn test_multiply_integer_polynomials_matches_field_ntt() {
+        let mut rng = ark_std::test_rng();
+        let a: Vec<i64> = (0..300).map(|_| rng.gen_range(-100_000..100_000)).collect();
+        let b: Vec<i64> = (0..200).map(|_| rng.gen_range(-100_000..100_000)).collect();
+
+        let to_field = |values: &[i64]| values.iter().map(|&x| Fr::from(x)).collect::<Vec<_>>();
+        let expected = crate::division::multiply(&to_field(&a), &to_field(&b));
+
+        let product = multiply_integer_polynomials(&a, &b);
+        assert_eq!(to_field(&product), expected);
+    }
+
+    #[test]
+    fn test_error_bound_grows_with_inputs() {
+        let small = convolution_error_bound(&[1.0; 8], &[1.0; 8]);
+        let large = convolution_error_bound(&[1e6; 8], &[1e6; 8]);
+        assert!(small > 0.0 && small < 1e-12);
+        assert!((large / small - 1e12).abs() / 1e12 < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "coefficients too large for an exact f64 convolution")]
+    fn test_multiply_integer_polynomials_rejects_large_coefficients() {
+        multiply_integer_polynomials(&[1 << 40; 1000], &[1 << 40; 1000]);
+    }
+}
@@ -0,0 +1,207 @@
+pub mod prover;
+pub mod verifier;
+
+use ark_ff::{FftField, PrimeField};
+use merkle::Digest;
+
+/// Parameters shared by the FRI prover and verifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FriConfig {
+    /// Codewords are `2^log_blowup` times longer than the degree bound.
+    pub log_blowup: u32,
+    /// Each round folds `2^log_folding_factor` evaluations into one.
+    pub log_folding_factor: u32,
+    pub num_queries: usize,
+    /// Folding stops once the degree bound is `2^log_final_poly_len`, and that
+    /// polynomial is sent in the clear.
+    pub log_final_poly_len: u32,
+}
+
+impl FriConfig {
+    pub fn new(log_blowup: u32, log_folding_factor: u32, num_queries: usize) -> Self {
+        assert!(log_blowup > 0, "blowup factor must be at least 2");
+        assert!(log_folding_factor > 0, "folding factor must be at least 2");
+        assert!(num_queries > 0, "need at least one query");
+        Self {
+            log_blowup,
+            log_folding_factor,
+            num_queries,
+            log_final_poly_len: 0,
+        }
+    }
+
+    pub fn with_log_final_poly_len(self, log_final_poly_len: u32) -> Self {
+        Self {
+            log_final_poly_len,
+            ..self
+        }
+    }
+
+    pub fn blowup(&self) -> usize {
+        1 << self.log_blowup
+    }
+
+    pub fn folding_factor(&self) -> usize {
+        1 << self.log_folding_factor
+    }
+
+    pub fn final_poly_len(&self) -> usize {
+        1 << self.log_final_poly_len
+    }
+
+    /// The smallest `final_poly_len · foldingᵏ` with `k ≥ 1` holding
+    /// `num_coeffs` coefficients, which is the bound the proof actually
+    /// attests to. At least one round is always folded, so the first layer
+    /// root commits to the codeword.
+    pub fn degree_bound(&self, num_coeffs: usize) -> usize {
+        let mut bound = self.final_poly_len() * self.folding_factor();
+        while bound < num_coeffs {
+            bound *= self.folding_factor();
+        }
+        bound
+    }
+
+    /// Number of committed folding layers for a polynomial of `num_coeffs` coefficients.
+    pub fn num_rounds(&self, num_coeffs: usize) -> usize {
+        let ratio = self.degree_bound(num_coeffs) / self.final_poly_len();
+        (ratio.trailing_zeros() / self.log_folding_factor) as usize
+    }
+
+    /// Size of the first codeword for a polynomial of `num_coeffs` coefficients.
+    pub fn domain_size(&self, num_coeffs: usize) -> usize {
+        self.degree_bound(num_coeffs) << self.log_blowup
+    }
+
+    /// Security under the usual conjecture that each query rejects a word far
+    /// from the code with probability `1 - ρ`, for rate `ρ = 2^-log_blowup`.
+    pub fn conjectured_security_bits(&self) -> f64 {
+        (self.num_queries as u32 * self.log_blowup) as f64
+    }
+
+    /// Provable security in the unique decoding regime: a query passes a word
+    /// at distance `(1 - ρ)/2` with probability `(1 + ρ)/2`, and each folding
+    /// round lets a bad challenge through with probability about `n/|F|`.
+    pub fn proven_security_bits<F: PrimeField>(&self, num_coeffs: usize) -> f64 {
+        let rate = 1.0 / self.blowup() as f64;
+        let query_bits = -(self.num_queries as f64) * ((1.0 + rate) / 2.0).log2();
+
+        let rounds = self.num_rounds(num_coeffs);
+        let commit_bits =
+            F::MODULUS_BIT_SIZE as f64 - ((rounds * self.domain_size(num_coeffs)) as f64).log2();
+
+        query_bits.min(commit_bits)
+    }
+
+    /// Queries needed for `security_bits` of conjectured security at the given blowup.
+    pub fn queries_for_security(security_bits: u32, log_blowup: u32) -> usize {
+        security_bits.div_ceil(log_blowup) as usize
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FriProof<F: PrimeField> {
+    /// Merkle root of every committed layer; the first is the commitment to the codeword.
+    pub layer_roots: Vec<Digest>,
+    /// Coefficients of the last folded polynomial.
+    pub final_polynomial: Vec<F>,
+    pub query_proofs: Vec<QueryProof<F>>,
+}
+
+/// The folding coset opened in every layer for one query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryProof<F: PrimeField> {
+    pub openings: Vec<LayerOpening<F>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerOpening<F: PrimeField> {
+    pub values: Vec<F>,
+    pub path: Vec<Digest>,
+}
+
+/// Folds the evaluations of `f` on the coset `{x·ζᵏ}` of `r`-th roots `ζᵏ`
+/// into the value at `xʳ` of `f'(y) = Σ βⁱ fᵢ(y)`, where `f(x) = Σ xⁱ fᵢ(xʳ)`.
+///
+/// Inverting the size-`r` DFT gives `f'(xʳ) = 1/r · Σₖ f(xₖ) · Σᵢ (β/xₖ)ⁱ`.
+pub(crate) fn fold_coset<F: FftField>(values: &[F], x_inv: F, zeta_inv: F, beta: F) -> F {
+    let r = values.len();
+    let mut point_inv = x_inv;
+    let mut sum = F::zero();
+    for value in values {
+        let ratio = beta * point_inv;
+        let mut power = F::one();
+        let mut geometric = F::zero();
+        for _ in 0..r {
+            geometric += power;
+            power *= ratio;
+        }
+        sum += *value * geometric;
+        point_inv *= zeta_inv;
+    }
+    sum * F::from(r as u64).inverse().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::{Field, UniformRand};
+
+    #[test]
+    fn test_degree_bound_and_rounds() {
+        let config = FriConfig::new(2, 2, 10);
+        assert_eq!(config.degree_bound(1), 4);
+        assert_eq!(config.num_rounds(1), 1);
+        assert_eq!(config.degree_bound(5), 16);
+        assert_eq!(config.num_rounds(5), 2);
+        assert_eq!(config.domain_size(5), 64);
+
+        let config = config.with_log_final_poly_len(1);
+        assert_eq!(config.degree_bound(5), 8);
+        assert_eq!(config.num_rounds(5), 1);
+
+        // a polynomial that already fits the final length is still folded once
+        let config = config.with_log_final_poly_len(3);
+        assert_eq!(config.degree_bound(5), 32);
+        assert_eq!(config.num_rounds(5), 1);
+    }
+
+    #[test]
+    fn test_fold_coset() {
+        let mut rng = ark_std::test_rng();
+        let r = 4;
+        let coefficients: Vec<Fr> = (0..8).map(|_| Fr::rand(&mut rng)).collect();
+        let evaluate = |x: Fr| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(Fr::from(0u64), |acc, c| acc * x + c)
+        };
+
+        let x = Fr::rand(&mut rng);
+        let zeta = Fr::get_root_of_unity(r).unwrap();
+        let values: Vec<Fr> = (0..r).map(|k| evaluate(x * zeta.pow([k]))).collect();
+        let beta = Fr::rand(&mut rng);
+
+        // fᵢ(y) = c_i + c_(i+4)·y
+        let y = x.pow([r]);
+        let expected: Fr = (0..r as usize)
+            .map(|i| beta.pow([i as u64]) * (coefficients[i] + coefficients[i + 4] * y))
+            .sum();
+        let folded = fold_coset(&values, x.inverse().unwrap(), zeta.inverse().unwrap(), beta);
+        assert_eq!(folded, expected);
+    }
+
+    #[test]
+    fn test_security_calculator() {
+        let config = FriConfig::new(3, 1, 30);
+        assert_eq!(config.conjectured_security_bits(), 90.0);
+        assert_eq!(FriConfig::queries_for_security(90, 3), 30);
+        assert_eq!(FriConfig::queries_for_security(100, 3), 34);
+
+        let proven = config.proven_security_bits::<Fr>(1 << 10);
+        // each query only gives -log2(9/16) ≈ 0.83 bits without the conjecture
+        assert!((proven - 30.0 * (16.0f64 / 9.0).log2()).abs() < 1e-9);
+        assert!(proven < config.conjectured_security_bits());
+    }
+}
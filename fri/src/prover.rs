@@ -0,0 +1,176 @@
+use crate::{FriConfig, FriProof, LayerOpening, QueryProof, fold_coset};
+use ark_ff::{BigInteger, PrimeField};
+use fft::domain::EvaluationDomain;
+use merkle::MerkleTree;
+use sha3::{Digest, Keccak256};
+use sum_check::transcript::Transcript;
+use univariate_poly::UnivariatePolynomial;
+
+/// One committed codeword over the coset `offset·⟨ω⟩`, stored leaf by leaf.
+struct Layer<F: PrimeField> {
+    leaves: Vec<Vec<F>>,
+    tree: MerkleTree<Keccak256>,
+}
+
+/// Proves that `polynomial` has fewer than `config.degree_bound(len)`
+/// coefficients, evaluating it over a coset of the blown-up domain.
+pub fn prove<F: PrimeField>(
+    config: &FriConfig,
+    polynomial: &UnivariatePolynomial<F>,
+) -> FriProof<F> {
+    let num_coeffs = polynomial.coefficients.len();
+    let folding = config.folding_factor();
+    let mut transcript = Transcript::<Keccak256, F>::init(Keccak256::new());
+    transcript.absorb(&(config.degree_bound(num_coeffs) as u64).to_be_bytes());
+
+    let domain = EvaluationDomain::<F>::new(config.domain_size(num_coeffs)).get_coset(F::GENERATOR);
+    let mut evaluations = domain.fft(&polynomial.coefficients);
+    let mut offset = domain.offset();
+    let mut generator = domain.generator();
+    let zeta_inv = generator
+        .pow([(domain.size() / folding) as u64])
+        .inverse()
+        .unwrap();
+
+    let mut layers = Vec::with_capacity(config.num_rounds(num_coeffs));
+    for _ in 0..config.num_rounds(num_coeffs) {
+        let leaves = coset_leaves(&evaluations, folding);
+        let tree = MerkleTree::new(&leaves);
+        transcript.absorb(&tree.root());
+        let beta: F = transcript.squeeze();
+
+        let generator_inv = generator.inverse().unwrap();
+        let mut x_inv = offset.inverse().unwrap();
+        evaluations = leaves
+            .iter()
+            .map(|leaf| {
+                let folded = fold_coset(leaf, x_inv, zeta_inv, beta);
+                x_inv *= generator_inv;
+                folded
+            })
+            .collect();
+
+        offset = offset.pow([folding as u64]);
+        generator = generator.pow([folding as u64]);
+        layers.push(Layer { leaves, tree });
+    }
+
+    let final_domain = EvaluationDomain::<F>::new(evaluations.len()).get_coset(offset);
+    let mut final_polynomial = final_domain.ifft(&evaluations);
+    assert!(
+        final_polynomial[config.final_poly_len()..]
+            .iter()
+            .all(|c| c.is_zero()),
+        "folded polynomial exceeds the final length"
+    );
+    final_polynomial.truncate(config.final_poly_len());
+    for coefficient in &final_polynomial {
+        transcript.absorb(&coefficient.into_bigint().to_bytes_be());
+    }
+
+    let query_proofs = query_positions(&mut transcript, config.num_queries, domain.size())
+        .into_iter()
+        .map(|mut position| {
+            let openings = layers
+                .iter()
+                .map(|layer| {
+                    let index = position % layer.leaves.len();
+                    position = index;
+                    LayerOpening {
+                        values: layer.leaves[index].clone(),
+                        path: layer.tree.open(index),
+                    }
+                })
+                .collect();
+            QueryProof { openings }
+        })
+        .collect();
+
+    FriProof {
+        layer_roots: layers.iter().map(|layer| layer.tree.root()).collect(),
+        final_polynomial,
+        query_proofs,
+    }
+}
+
+/// Groups the codeword into the cosets folded together: leaf `j` holds the
+/// values at positions `j, j + n/r, ..., j + (r-1)·n/r`.
+fn coset_leaves<F: PrimeField>(evaluations: &[F], folding: usize) -> Vec<Vec<F>> {
+    let num_leaves = evaluations.len() / folding;
+    (0..num_leaves)
+        .map(|j| {
+            (0..folding)
+                .map(|k| evaluations[j + k * num_leaves])
+                .collect()
+        })
+        .collect()
+}
+
+/// Draws the query positions in the first codeword from the transcript.
+pub(crate) fn query_positions<F: PrimeField>(
+    transcript: &mut Transcript<Keccak256, F>,
+    num_queries: usize,
+    domain_size: usize,
+) -> Vec<usize> {
+    (0..num_queries)
+        .map(|i| {
+            transcript.absorb(&(i as u64).to_be_bytes());
+            let challenge: F = transcript.squeeze();
+            challenge.into_bigint().as_ref()[0] as usize % domain_size
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::UniformRand;
+
+    #[test]
+    fn test_proof_shape() {
+        let mut rng = ark_std::test_rng();
+        let polynomial = UnivariatePolynomial::new((0..60).map(|_| Fr::rand(&mut rng)).collect());
+        let config = FriConfig::new(2, 2, 8).with_log_final_poly_len(2);
+
+        // 60 coefficients → bound 64 = 4·4², so two rounds over a 256-point coset
+        let proof = prove(&config, &polynomial);
+        assert_eq!(proof.layer_roots.len(), 2);
+        assert_eq!(proof.final_polynomial.len(), 4);
+        assert_eq!(proof.query_proofs.len(), 8);
+        for query in &proof.query_proofs {
+            assert_eq!(query.openings[0].path.len(), 6);
+            assert_eq!(query.openings[1].path.len(), 4);
+            assert!(
+                query
+                    .openings
+                    .iter()
+                    .all(|opening| opening.values.len() == 4)
+            );
+        }
+    }
+
+    #[test]
+    fn test_coset_leaves() {
+        let evaluations: Vec<Fr> = (0..8u64).map(Fr::from).collect();
+        let leaves = coset_leaves(&evaluations, 4);
+        assert_eq!(
+            leaves[0],
+            vec![
+                Fr::from(0u64),
+                Fr::from(2u64),
+                Fr::from(4u64),
+                Fr::from(6u64)
+            ]
+        );
+        assert_eq!(
+            leaves[1],
+            vec![
+                Fr::from(1u64),
+                Fr::from(3u64),
+                Fr::from(5u64),
+                Fr::from(7u64)
+            ]
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::prover::query_positions;
+use crate::{FriConfig, FriProof, fold_coset};
+use ark_ff::{BigInteger, PrimeField};
+use merkle::verify_path;
+use sha3::{Digest, Keccak256};
+use sum_check::transcript::Transcript;
+
+/// Checks that `proof` attests to a polynomial of fewer than
+/// `config.degree_bound(num_coeffs)` coefficients committed in `proof.layer_roots[0]`.
+pub fn verify<F: PrimeField>(config: &FriConfig, num_coeffs: usize, proof: &FriProof<F>) -> bool {
+    let num_rounds = config.num_rounds(num_coeffs);
+    if proof.layer_roots.len() != num_rounds
+        || proof.final_polynomial.len() != config.final_poly_len()
+        || proof.query_proofs.len() != config.num_queries
+    {
+        return false;
+    }
+
+    let folding = config.folding_factor();
+    let mut transcript = Transcript::<Keccak256, F>::init(Keccak256::new());
+    transcript.absorb(&(config.degree_bound(num_coeffs) as u64).to_be_bytes());
+    let betas: Vec<F> = proof
+        .layer_roots
+        .iter()
+        .map(|root| {
+            transcript.absorb(root);
+            transcript.squeeze()
+        })
+        .collect();
+    for coefficient in &proof.final_polynomial {
+        transcript.absorb(&coefficient.into_bigint().to_bytes_be());
+    }
+
+    let domain_size = config.domain_size(num_coeffs);
+    let generator = F::get_root_of_unity(domain_size as u64).unwrap();
+    let zeta_inv = generator
+        .pow([(domain_size / folding) as u64])
+        .inverse()
+        .unwrap();
+    let positions = query_positions(&mut transcript, config.num_queries, domain_size);
+
+    positions
+        .into_iter()
+        .zip(&proof.query_proofs)
+        .all(|(mut position, query)| {
+            if query.openings.len() != num_rounds {
+                return false;
+            }
+
+            let mut size = domain_size;
+            let mut offset = F::GENERATOR;
+            let mut generator = generator;
+            let mut expected = None;
+            for ((opening, root), beta) in query.openings.iter().zip(&proof.layer_roots).zip(&betas)
+            {
+                let num_leaves = size / folding;
+                let (index, slot) = (position % num_leaves, position / num_leaves);
+                if opening.values.len() != folding
+                    || !verify_path::<Keccak256, F>(root, index, &opening.values, &opening.path)
+                    || expected.is_some_and(|value| opening.values[slot] != value)
+                {
+                    return false;
+                }
+
+                let x = offset * generator.pow([index as u64]);
+                expected = Some(fold_coset(
+                    &opening.values,
+                    x.inverse().unwrap(),
+                    zeta_inv,
+                    *beta,
+                ));
+
+                position = index;
+                size = num_leaves;
+                offset = offset.pow([folding as u64]);
+                generator = generator.pow([folding as u64]);
+            }
+
+            let point = offset * generator.pow([position as u64]);
+            let final_value = proof
+                .final_polynomial
+                .iter()
+                .rev()
+                .fold(F::zero(), |acc, coefficient| acc * point + coefficient);
+            expected.is_none_or(|value| value == final_value)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prover::prove;
+    use ark_bn254::Fr;
+    use ark_ff::UniformRand;
+    use univariate_poly::UnivariatePolynomial;
+
+    fn random_polynomial(num_coeffs: usize) -> UnivariatePolynomial<Fr> {
+        let mut rng = ark_std::test_rng();
+        UnivariatePolynomial::new((0..num_coeffs).map(|_| Fr::rand(&mut rng)).collect())
+    }
+
+    #[test]
+    fn test_honest_proofs_verify() {
+        for (config, num_coeffs) in [
+            (FriConfig::new(1, 1, 20), 64),
+            (FriConfig::new(2, 2, 12).with_log_final_poly_len(2), 60),
+            (FriConfig::new(3, 3, 8), 100),
+            (FriConfig::new(2, 1, 4).with_log_final_poly_len(3), 5),
+        ] {
+            let polynomial = random_polynomial(num_coeffs);
+            let proof = prove(&config, &polynomial);
+            assert!(verify(&config, num_coeffs, &proof));
+        }
+    }
+
+    #[test]
+    fn test_tampered_proof_fails() {
+        let config = FriConfig::new(2, 1, 10);
+        let proof = prove(&config, &random_polynomial(32));
+
+        let mut bad_value = proof.clone();
+        bad_value.query_proofs[0].openings[1].values[0] += Fr::from(1u64);
+        assert!(!verify(&config, 32, &bad_value));
+
+        let mut bad_final = proof.clone();
+        bad_final.final_polynomial[0] += Fr::from(1u64);
+        assert!(!verify(&config, 32, &bad_final));
+
+        let mut bad_root = proof;
+        bad_root.layer_roots[2][0] ^= 1;
+        assert!(!verify(&config, 32, &bad_root));
+    }
+
+    #[test]
+    fn test_polynomial_within_final_length_is_committed() {
+        // 5 coefficients fit the final length 8, yet the codeword is still committed
+        let config = FriConfig::new(2, 1, 6).with_log_final_poly_len(3);
+        let proof = prove(&config, &random_polynomial(5));
+        assert_eq!(proof.layer_roots.len(), 1);
+        assert!(verify(&config, 5, &proof));
+
+        let mut bad_root = proof.clone();
+        bad_root.layer_roots[0][0] ^= 1;
+        assert!(!verify(&config, 5, &bad_root));
+
+        let mut no_rounds = proof;
+        no_rounds.layer_roots.clear();
+        for query in &mut no_rounds.query_proofs {
+            query.openings.clear();
+        }
+        assert!(!verify(&config, 5, &no_rounds));
+    }
+
+    #[test]
+    fn test_wrong_shape_fails() {
+        let config = FriConfig::new(2, 1, 10);
+        let proof = prove(&config, &random_polynomial(32));
+        assert!(!verify(&config, 64, &proof));
+        assert!(!verify(&FriConfig::new(2, 1, 11), 32, &proof));
+    }
+}
@@ -30,7 +30,7 @@ pub fn prove<F: PrimeField>(circuit: &mut Circuit<F>) -> Proof<F> {
         w_0_polynomial = MultilinearPolynomial::new(padded_w_0);
     }
 
-    transcript.absorb(&w_0_polynomial.convert_to_bytes());
+    transcript.absorb_serializable(&w_0_polynomial);
     let challenge_a = transcript.squeeze();
     let mut claimed_sum = w_0_polynomial.evaluate(&vec![challenge_a]);
 
@@ -104,7 +104,7 @@ pub fn verify<F: PrimeField>(proof: Proof<F>, circuit: &mut Circuit<F>) -> bool
         w_0_polynomial = MultilinearPolynomial::new(padded_w_0);
     }
 
-    transcript.absorb(&w_0_polynomial.convert_to_bytes());
+    transcript.absorb_serializable(&w_0_polynomial);
     let challenge_a = transcript.squeeze();
     let mut claimed_sum = w_0_polynomial.evaluate(&vec![challenge_a]);
 
@@ -117,7 +117,9 @@ pub fn verify<F: PrimeField>(proof: Proof<F>, circuit: &mut Circuit<F>) -> bool
             return false;
         }
 
-        let sumcheck_verif = partial_verify(&proof.sumcheck_proofs[layer_index], &mut transcript);
+        // each layer sums products of two multilinear factors
+        let sumcheck_verif =
+            partial_verify(&proof.sumcheck_proofs[layer_index], 2, &mut transcript);
         if !sumcheck_verif.is_proof_valid {
             return false;
         }
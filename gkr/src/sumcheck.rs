@@ -2,12 +2,13 @@ use ark_ff::{BigInteger, PrimeField};
 use multivariate_poly::sum_poly::SumPoly;
 use sha3::Keccak256;
 use sum_check::transcript::Transcript;
-use univariate_poly::UnivariatePolynomial;
+use univariate_poly::barycentric::BarycentricDomain;
 
 #[derive(Debug, Clone)]
 pub struct PartialProof<F: PrimeField> {
     pub claimed_sum: F,
-    pub round_polys: Vec<UnivariatePolynomial<F>>,
+    /// Each round polynomial as its evaluations at `0, 1, ..., d`.
+    pub round_polys: Vec<Vec<F>>,
     pub random_challenges: Vec<F>,
 }
 
@@ -32,24 +33,10 @@ pub fn partial_prove<F: PrimeField>(
     let no_of_variables = sum_poly.no_of_variables();
 
     for _ in 0..no_of_variables {
-        let round_split = split_and_sum(current_poly.clone());
+        let round_evaluations = split_and_sum(current_poly.clone());
 
-        let x_values: Vec<F> = (0..=sum_poly.degree()).map(|i| F::from(i as u64)).collect();
-        let y_values: Vec<F> = round_split;
-
-        let points: Vec<(F, F)> = x_values
-            .iter()
-            .zip(y_values.iter())
-            .map(|(x, y)| (*x, *y))
-            .collect();
-
-        dbg!(&points);
-
-        let univariate_poly = UnivariatePolynomial::interpolate(points);
-        dbg!(&univariate_poly);
-
-        transcript.absorb(univariate_poly.convert_to_bytes().as_slice());
-        round_polys.push(univariate_poly);
+        transcript.absorb_serializable(&round_evaluations);
+        round_polys.push(round_evaluations);
 
         let challenge: F = transcript.squeeze();
         current_poly = current_poly.partial_evaluate(0, challenge);
@@ -64,8 +51,11 @@ pub fn partial_prove<F: PrimeField>(
     }
 }
 
+/// Checks the rounds of `proof` for a sum polynomial of the given `degree`,
+/// so every round polynomial must carry exactly `degree + 1` evaluations.
 pub fn partial_verify<F: PrimeField>(
     proof: &PartialProof<F>,
+    degree: usize,
     transcript: &mut Transcript<Keccak256, F>,
 ) -> PartialVerif<F> {
     // transcript.absorb(sum_poly.convert_to_bytes().as_slice());
@@ -73,9 +63,12 @@ pub fn partial_verify<F: PrimeField>(
 
     let mut current_claimed_sum = proof.claimed_sum;
     let mut challenges = Vec::with_capacity(proof.round_polys.len());
+    let domain = BarycentricDomain::integers(degree + 1);
 
     for round_poly in &proof.round_polys {
-        if round_poly.evaluate(F::from(0)) + round_poly.evaluate(F::from(1)) != current_claimed_sum
+        if round_poly.len() < 2
+            || round_poly.len() != degree + 1
+            || round_poly[0] + round_poly[1] != current_claimed_sum
         {
             return PartialVerif {
                 is_proof_valid: false,
@@ -84,10 +77,10 @@ pub fn partial_verify<F: PrimeField>(
             };
         }
 
-        transcript.absorb(round_poly.convert_to_bytes().as_slice());
+        transcript.absorb_serializable(round_poly);
         let challenge: F = transcript.squeeze();
 
-        current_claimed_sum = round_poly.evaluate(challenge);
+        current_claimed_sum = domain.evaluate(round_poly, challenge);
         challenges.push(challenge);
     }
 
@@ -150,7 +143,32 @@ mod tests {
 
         let proof = partial_prove(sum_poly.clone(), Fq::from(13), &mut prover_transcript);
         dbg!(&proof);
-        let verify = partial_verify(&proof, &mut verifier_transcript);
+        let verify = partial_verify(&proof, sum_poly.degree(), &mut verifier_transcript);
         assert_eq!(verify.is_proof_valid, true);
     }
+
+    #[test]
+    fn test_sumcheck_rejects_wrong_round_degree() {
+        let mul1 = MultilinearPolynomial::new(to_field(vec![0, 0, 0, 2]));
+        let mul2 = MultilinearPolynomial::new(to_field(vec![0, 0, 0, 3]));
+        let mul3 = MultilinearPolynomial::new(to_field(vec![0, 0, 0, 1]));
+        let mul4 = MultilinearPolynomial::new(to_field(vec![0, 0, 0, 7]));
+        let sum_poly = SumPoly::new(vec![
+            ProductPoly::new(vec![mul1, mul2]),
+            ProductPoly::new(vec![mul3, mul4]),
+        ]);
+        let mut prover_transcript = Transcript::<Keccak256, Fq>::init(Keccak256::default());
+        let proof = partial_prove(sum_poly.clone(), Fq::from(13), &mut prover_transcript);
+
+        // an extra evaluation would raise the degree the verifier interpolates
+        let mut padded = proof.clone();
+        padded.round_polys[0].push(Fq::from(5));
+        let mut verifier_transcript = Transcript::<Keccak256, Fq>::init(Keccak256::default());
+        let verify = partial_verify(&padded, sum_poly.degree(), &mut verifier_transcript);
+        assert!(!verify.is_proof_valid);
+
+        let mut verifier_transcript = Transcript::<Keccak256, Fq>::init(Keccak256::default());
+        let verify = partial_verify(&proof, 1, &mut verifier_transcript);
+        assert!(!verify.is_proof_valid);
+    }
 }
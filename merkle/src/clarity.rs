@@ -0,0 +1,364 @@
+//! SHA-256 Merkle trees that a Clarity contract can check with `sha256` and
+//! `concat` alone.
+//!
+//! Leaves are raw byte strings, usually the `to-consensus-buff?` encoding of
+//! a Clarity value (see [`ClarityValue`]). A leaf hashes as
+//! `sha256(0x00 ‖ leaf)` and a node as `sha256(0x01 ‖ left ‖ right)`. Trees
+//! with a leaf count that is not a power of 2 are padded with the all-zero
+//! hash, which has no known preimage. A proof lists the siblings from the
+//! leaf upwards, and bit `i` of the leaf index says whether the running
+//! hash is the right child at height `i`. The index must be below
+//! `2^len(proof)`: higher bits are never read, so without that check
+//! `index + 2^len(proof)` would pass with the same proof. A contract verifies
+//! it like this:
+//!
+//! ```clarity
+//! (define-private (hash-step (sibling (buff 32)) (state { hash: (buff 32), index: uint }))
+//!   (let ((index (get index state)))
+//!     { hash: (if (is-eq (mod index u2) u0)
+//!                 (sha256 (concat 0x01 (concat (get hash state) sibling)))
+//!                 (sha256 (concat 0x01 (concat sibling (get hash state))))),
+//!       index: (/ index u2) }))
+//!
+//! (define-read-only (verify-proof (root (buff 32)) (leaf (buff 256)) (index uint)
+//!                                 (proof (list 32 (buff 32))))
+//!   (begin
+//!     (asserts! (< index (pow u2 (len proof))) false)
+//!     (is-eq root (get hash (fold hash-step proof
+//!                                 { hash: (sha256 (concat 0x00 leaf)), index: index })))))
+//! ```
+//!
+//! `test_vectors/clarity_sha256.json` holds trees and proofs for contract
+//! tests, plus proofs of leaf 0 at the out-of-range index `2^len(proof)` that
+//! must be rejected.
+
+use crate::{Digest, MerkleTree, leaf_hash_bytes, verify_leaf_hash};
+use sha2::Sha256;
+
+pub type Hash = [u8; 32];
+
+/// Stands in for the leaves that pad the tree to a power of 2.
+pub const EMPTY_LEAF_HASH: Hash = [0; 32];
+
+#[derive(Debug, Clone)]
+pub struct ClarityMerkleTree {
+    tree: MerkleTree<Sha256>,
+    num_leaves: usize,
+}
+
+impl ClarityMerkleTree {
+    pub fn new<L: AsRef<[u8]>>(leaves: &[L]) -> Self {
+        assert!(!leaves.is_empty(), "need at least one leaf");
+        let mut leaf_hashes: Vec<Digest> = leaves
+            .iter()
+            .map(|leaf| leaf_hash_bytes::<Sha256>(leaf.as_ref()))
+            .collect();
+        leaf_hashes.resize(leaves.len().next_power_of_two(), EMPTY_LEAF_HASH.to_vec());
+
+        Self {
+            tree: MerkleTree::from_leaf_hashes(leaf_hashes),
+            num_leaves: leaves.len(),
+        }
+    }
+
+    pub fn root(&self) -> Hash {
+        to_hash(&self.tree.root())
+    }
+
+    pub fn num_leaves(&self) -> usize {
+        self.num_leaves
+    }
+
+    /// Siblings from the leaf at `index` to the root, as the contract's
+    /// `(list 32 (buff 32))` argument.
+    pub fn proof(&self, index: usize) -> Vec<Hash> {
+        assert!(index < self.num_leaves, "leaf index out of range");
+        self.tree
+            .open(index)
+            .iter()
+            .map(|hash| to_hash(hash))
+            .collect()
+    }
+}
+
+/// The Rust side of the contract's `verify-proof`.
+pub fn verify_proof(root: &Hash, index: usize, leaf: &[u8], proof: &[Hash]) -> bool {
+    let path: Vec<Digest> = proof.iter().map(|hash| hash.to_vec()).collect();
+    verify_leaf_hash::<Sha256>(root, index, leaf_hash_bytes::<Sha256>(leaf), &path)
+}
+
+fn to_hash(digest: &[u8]) -> Hash {
+    digest.try_into().expect("sha256 digests are 32 bytes")
+}
+
+/// A Clarity value, serialised exactly as `to-consensus-buff?` does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClarityValue {
+    Int(i128),
+    UInt(u128),
+    Bool(bool),
+    Buffer(Vec<u8>),
+    StringAscii(String),
+    /// `version` is 22 (`SP…`) on mainnet and 26 (`ST…`) on testnet.
+    StandardPrincipal {
+        version: u8,
+        hash160: [u8; 20],
+    },
+    Tuple(Vec<(String, ClarityValue)>),
+}
+
+impl ClarityValue {
+    pub fn to_consensus_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        self.serialize_into(&mut bytes);
+        bytes
+    }
+
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Self::Int(value) => {
+                bytes.push(0x00);
+                bytes.extend(value.to_be_bytes());
+            }
+            Self::UInt(value) => {
+                bytes.push(0x01);
+                bytes.extend(value.to_be_bytes());
+            }
+            Self::Buffer(data) => {
+                bytes.push(0x02);
+                bytes.extend((data.len() as u32).to_be_bytes());
+                bytes.extend(data);
+            }
+            Self::Bool(value) => bytes.push(if *value { 0x03 } else { 0x04 }),
+            Self::StandardPrincipal { version, hash160 } => {
+                bytes.push(0x05);
+                bytes.push(*version);
+                bytes.extend(hash160);
+            }
+            Self::Tuple(fields) => {
+                // tuples are stored sorted by field name
+                let mut fields: Vec<_> = fields.iter().collect();
+                fields.sort_by(|a, b| a.0.cmp(&b.0));
+                bytes.push(0x0c);
+                bytes.extend((fields.len() as u32).to_be_bytes());
+                for (name, value) in fields {
+                    assert!(name.len() <= 128, "clarity names are at most 128 bytes");
+                    bytes.push(name.len() as u8);
+                    bytes.extend(name.as_bytes());
+                    value.serialize_into(bytes);
+                }
+            }
+            Self::StringAscii(text) => {
+                assert!(text.is_ascii(), "string-ascii must be ASCII");
+                bytes.push(0x0d);
+                bytes.extend((text.len() as u32).to_be_bytes());
+                bytes.extend(text.as_bytes());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::from("0x"), |mut out, byte| {
+            write!(out, "{byte:02x}").unwrap();
+            out
+        })
+    }
+
+    fn allocation(hash_byte: u8, amount: u128) -> Vec<u8> {
+        ClarityValue::Tuple(vec![
+            (
+                "recipient".into(),
+                ClarityValue::StandardPrincipal {
+                    version: 26,
+                    hash160: [hash_byte; 20],
+                },
+            ),
+            ("amount".into(), ClarityValue::UInt(amount)),
+        ])
+        .to_consensus_bytes()
+    }
+
+    fn vector_cases() -> Vec<(&'static str, Vec<Vec<u8>>)> {
+        vec![
+            ("single-leaf", vec![b"stacks".to_vec()]),
+            (
+                "ascii-leaves",
+                ["a", "b", "c"]
+                    .iter()
+                    .map(|s| s.as_bytes().to_vec())
+                    .collect(),
+            ),
+            (
+                "uint-leaves",
+                (1..=5)
+                    .map(|i| ClarityValue::UInt(i).to_consensus_bytes())
+                    .collect(),
+            ),
+            (
+                "allocations",
+                vec![
+                    allocation(0x11, 1_000_000),
+                    allocation(0x22, 250_000),
+                    allocation(0x33, 42),
+                    allocation(0x44, 7),
+                ],
+            ),
+        ]
+    }
+
+    /// Leaf 0's proof at index `2^len(proof)`, which folds to the root when
+    /// the index is not range checked.
+    fn out_of_range_proof(tree: &ClarityMerkleTree) -> (usize, Vec<Hash>) {
+        let proof = tree.proof(0);
+        (1 << proof.len(), proof)
+    }
+
+    /// Renders the vectors file. Run the tests with `UPDATE_TEST_VECTORS=1`
+    /// to rewrite it after changing the cases.
+    fn vectors_json() -> String {
+        let mut out = String::from("{\n");
+        out += "  \"hash\": \"sha256\",\n";
+        out += "  \"leaf_hash\": \"sha256(0x00 || leaf)\",\n";
+        out += "  \"node_hash\": \"sha256(0x01 || left || right)\",\n";
+        out += "  \"padding\": \"leaf hashes padded to a power of 2 with 32 zero bytes\",\n";
+        out += "  \"rejected_proofs\": \"leaf 0 at index 2^len(siblings); verify-proof must return false\",\n";
+        out += "  \"cases\": [\n";
+
+        let cases = vector_cases();
+        for (c, (name, leaves)) in cases.iter().enumerate() {
+            let tree = ClarityMerkleTree::new(leaves);
+            let list = |items: Vec<String>| items.join(", ");
+
+            out += "    {\n";
+            writeln!(out, "      \"name\": \"{name}\",").unwrap();
+            let leaves_hex = leaves.iter().map(|leaf| format!("\"{}\"", hex(leaf)));
+            writeln!(out, "      \"leaves\": [{}],", list(leaves_hex.collect())).unwrap();
+            writeln!(out, "      \"root\": \"{}\",", hex(&tree.root())).unwrap();
+            out += "      \"proofs\": [\n";
+            for index in 0..leaves.len() {
+                let siblings = tree.proof(index);
+                let siblings = siblings.iter().map(|s| format!("\"{}\"", hex(s)));
+                write!(
+                    out,
+                    "        {{ \"index\": {index}, \"siblings\": [{}] }}",
+                    list(siblings.collect())
+                )
+                .unwrap();
+                out += if index + 1 < leaves.len() {
+                    ",\n"
+                } else {
+                    "\n"
+                };
+            }
+            out += "      ],\n";
+            let (index, siblings) = out_of_range_proof(&tree);
+            let siblings = siblings.iter().map(|s| format!("\"{}\"", hex(s)));
+            writeln!(
+                out,
+                "      \"rejected_proofs\": [{{ \"leaf\": 0, \"index\": {index}, \"siblings\": [{}] }}]",
+                list(siblings.collect())
+            )
+            .unwrap();
+            out += if c + 1 < cases.len() {
+                "    },\n"
+            } else {
+                "    }\n"
+            };
+        }
+        out += "  ]\n}\n";
+        out
+    }
+
+    #[test]
+    fn test_vectors_are_current() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test_vectors/clarity_sha256.json"
+        );
+        if std::env::var_os("UPDATE_TEST_VECTORS").is_some() {
+            std::fs::write(path, vectors_json()).unwrap();
+        }
+        assert_eq!(std::fs::read_to_string(path).unwrap(), vectors_json());
+    }
+
+    #[test]
+    fn test_known_root() {
+        // sha256(0x01 ‖ sha256(0x00 ‖ "a") ‖ sha256(0x00 ‖ "b")), computed independently
+        let tree = ClarityMerkleTree::new(&[b"a", b"b"]);
+        assert_eq!(
+            hex(&tree.root()),
+            "0xb137985ff484fb600db93107c77b0365c80d78f5b429ded0fd97361d077999eb"
+        );
+    }
+
+    #[test]
+    fn test_proofs_verify() {
+        for (_, leaves) in vector_cases() {
+            let tree = ClarityMerkleTree::new(&leaves);
+            for (i, leaf) in leaves.iter().enumerate() {
+                assert!(verify_proof(&tree.root(), i, leaf, &tree.proof(i)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_index_is_rejected() {
+        for (_, leaves) in vector_cases() {
+            let tree = ClarityMerkleTree::new(&leaves);
+            let (index, proof) = out_of_range_proof(&tree);
+
+            // only the range check stops this: the fold ignores the high bit
+            let path: Vec<Digest> = proof.iter().map(|hash| hash.to_vec()).collect();
+            let mut hash = leaf_hash_bytes::<Sha256>(&leaves[0]);
+            for sibling in &path {
+                hash = crate::node_hash::<Sha256>(&hash, sibling);
+            }
+            assert_eq!(hash, tree.root());
+
+            assert!(!verify_proof(&tree.root(), index, &leaves[0], &proof));
+        }
+    }
+
+    #[test]
+    fn test_padding_is_not_a_valid_leaf() {
+        let leaves = [b"a", b"b", b"c"];
+        let tree = ClarityMerkleTree::new(&leaves);
+        let padding_path = tree.tree.open(3);
+        let padding_path: Vec<Hash> = padding_path.iter().map(|h| to_hash(h)).collect();
+        assert!(!verify_proof(&tree.root(), 3, &[], &padding_path));
+        assert!(!verify_proof(&tree.root(), 2, b"a", &tree.proof(2)));
+    }
+
+    #[test]
+    fn test_consensus_serialization() {
+        assert_eq!(
+            hex(&ClarityValue::UInt(1).to_consensus_bytes()),
+            "0x0100000000000000000000000000000001"
+        );
+        assert_eq!(
+            hex(&ClarityValue::Int(-1).to_consensus_bytes()),
+            "0x00ffffffffffffffffffffffffffffffff"
+        );
+        assert_eq!(
+            hex(&ClarityValue::Buffer(vec![0xde, 0xad]).to_consensus_bytes()),
+            "0x0200000002dead"
+        );
+        assert_eq!(
+            hex(&ClarityValue::StringAscii("hi".into()).to_consensus_bytes()),
+            "0x0d000000026869"
+        );
+        assert_eq!(hex(&ClarityValue::Bool(false).to_consensus_bytes()), "0x04");
+        // fields come out sorted by name
+        let tuple = ClarityValue::Tuple(vec![
+            ("b".into(), ClarityValue::Bool(true)),
+            ("a".into(), ClarityValue::Bool(false)),
+        ]);
+        assert_eq!(hex(&tuple.to_consensus_bytes()), "0x0c00000002016104016203");
+    }
+}
@@ -0,0 +1,254 @@
+pub mod clarity;
+pub mod multiproof;
+
+use ark_ff::{BigInteger, PrimeField};
+use std::marker::PhantomData;
+use sum_check::transcript::HashTrait;
+
+pub use multiproof::{MultiProof, verify_multi_proof};
+
+/// Output of the tree's hash function.
+pub type Digest = Vec<u8>;
+
+/// Prefix of every leaf hash, so a leaf can never be read as an inner node.
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Binary Merkle tree over rows of field elements, generic over the hash.
+///
+/// Each leaf is one row, hashed as `H(0 ‖ row)` with every element in
+/// big-endian bytes; inner nodes are `H(1 ‖ left ‖ right)`.
+#[derive(Debug, Clone)]
+pub struct MerkleTree<H: HashTrait + Default> {
+    /// `layers[0]` holds the leaf hashes, the last layer the root alone.
+    layers: Vec<Vec<Digest>>,
+    _hash: PhantomData<H>,
+}
+
+impl<H: HashTrait + Default> MerkleTree<H> {
+    /// Commits to `leaves`, whose number must be a power of 2.
+    pub fn new<F: PrimeField>(leaves: &[Vec<F>]) -> Self {
+        Self::from_leaf_hashes(leaves.iter().map(|leaf| leaf_hash::<H, F>(leaf)).collect())
+    }
+
+    /// Builds the tree above already hashed leaves, for leaves that are raw
+    /// bytes rather than field elements.
+    pub fn from_leaf_hashes(leaf_hashes: Vec<Digest>) -> Self {
+        assert!(
+            leaf_hashes.len().is_power_of_two(),
+            "number of leaves must be a power of 2"
+        );
+
+        let mut layers = vec![leaf_hashes];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| node_hash::<H>(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+
+        Self {
+            layers,
+            _hash: PhantomData,
+        }
+    }
+
+    pub fn root(&self) -> Digest {
+        self.layers.last().unwrap()[0].clone()
+    }
+
+    pub fn num_leaves(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// Number of hashes in an authentication path.
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// Sibling hashes from the leaf at `index` up to the root.
+    pub fn open(&self, mut index: usize) -> Vec<Digest> {
+        assert!(index < self.num_leaves(), "leaf index out of range");
+        let mut path = Vec::with_capacity(self.depth());
+        for layer in &self.layers[..self.depth()] {
+            path.push(layer[index ^ 1].clone());
+            index /= 2;
+        }
+        path
+    }
+
+    /// Opens several leaves at once, sending each sibling only when the
+    /// verifier cannot recompute it from the other opened leaves.
+    pub fn open_multi(&self, indices: &[usize]) -> MultiProof {
+        multiproof::open(&self.layers, indices)
+    }
+}
+
+/// Checks that `leaf` sits at `index` under `root`.
+pub fn verify_path<H: HashTrait + Default, F: PrimeField>(
+    root: &[u8],
+    index: usize,
+    leaf: &[F],
+    path: &[Digest],
+) -> bool {
+    verify_leaf_hash::<H>(root, index, leaf_hash::<H, F>(leaf), path)
+}
+
+/// [`verify_path`] for a leaf that is already hashed.
+pub fn verify_leaf_hash<H: HashTrait + Default>(
+    root: &[u8],
+    mut index: usize,
+    mut hash: Digest,
+    path: &[Digest],
+) -> bool {
+    if exceeds_depth(index, path.len()) {
+        return false;
+    }
+
+    for sibling in path {
+        hash = if index.is_multiple_of(2) {
+            node_hash::<H>(&hash, sibling)
+        } else {
+            node_hash::<H>(sibling, &hash)
+        };
+        index /= 2;
+    }
+    hash == root
+}
+
+/// Whether `index` does not fit a tree of `depth` layers, including depths
+/// too large for any tree.
+pub(crate) fn exceeds_depth(index: usize, depth: usize) -> bool {
+    u32::try_from(depth)
+        .ok()
+        .and_then(|depth| index.checked_shr(depth))
+        .is_none_or(|high| high != 0)
+}
+
+pub fn leaf_hash<H: HashTrait + Default, F: PrimeField>(leaf: &[F]) -> Digest {
+    let mut hasher = H::default();
+    hasher.append(&[LEAF_PREFIX]);
+    for value in leaf {
+        hasher.append(&value.into_bigint().to_bytes_be());
+    }
+    hasher.generate_hash()
+}
+
+/// `H(0 ‖ bytes)`, the hash of a leaf given as raw bytes.
+pub fn leaf_hash_bytes<H: HashTrait + Default>(bytes: &[u8]) -> Digest {
+    let mut hasher = H::default();
+    hasher.append(&[LEAF_PREFIX]);
+    hasher.append(bytes);
+    hasher.generate_hash()
+}
+
+pub fn node_hash<H: HashTrait + Default>(left: &[u8], right: &[u8]) -> Digest {
+    let mut hasher = H::default();
+    hasher.append(&[NODE_PREFIX]);
+    hasher.append(left);
+    hasher.append(right);
+    hasher.generate_hash()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use sha3::Keccak256;
+
+    pub(crate) fn leaves(n: usize) -> Vec<Vec<Fr>> {
+        (0..n as u64)
+            .map(|i| vec![Fr::from(i), Fr::from(i * i)])
+            .collect()
+    }
+
+    #[test]
+    fn test_paths_verify() {
+        let leaves = leaves(8);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        assert_eq!(tree.depth(), 3);
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert!(verify_path::<Keccak256, _>(
+                &tree.root(),
+                i,
+                leaf,
+                &tree.open(i)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_wrong_leaf_or_index_fails() {
+        let leaves = leaves(8);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        let path = tree.open(3);
+        assert!(!verify_path::<Keccak256, _>(
+            &tree.root(),
+            3,
+            &leaves[4],
+            &path
+        ));
+        assert!(!verify_path::<Keccak256, _>(
+            &tree.root(),
+            2,
+            &leaves[3],
+            &path
+        ));
+        assert!(!verify_path::<Keccak256, _>(
+            &tree.root(),
+            11,
+            &leaves[3],
+            &path
+        ));
+    }
+
+    #[test]
+    fn test_overlong_path_fails() {
+        let leaves = leaves(8);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        let mut path = tree.open(3);
+        path.resize(64, tree.root());
+        assert!(!verify_path::<Keccak256, _>(
+            &tree.root(),
+            3,
+            &leaves[3],
+            &path
+        ));
+        path.push(tree.root());
+        assert!(!verify_path::<Keccak256, _>(
+            &tree.root(),
+            3,
+            &leaves[3],
+            &path
+        ));
+    }
+
+    #[test]
+    fn test_single_leaf() {
+        let leaves = leaves(1);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        assert!(tree.open(0).is_empty());
+        assert_eq!(tree.root(), leaf_hash::<Keccak256, _>(&leaves[0]));
+    }
+
+    #[test]
+    fn test_leaf_and_node_hashes_are_separated() {
+        // a two-element leaf must not hash like the node above two children
+        let left = Fr::from(1u64);
+        let right = Fr::from(2u64);
+        let as_node = node_hash::<Keccak256>(
+            &left.into_bigint().to_bytes_be(),
+            &right.into_bigint().to_bytes_be(),
+        );
+        assert_ne!(leaf_hash::<Keccak256, _>(&[left, right]), as_node);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of leaves must be a power of 2")]
+    fn test_leaf_count_must_be_power_of_two() {
+        MerkleTree::<Keccak256>::new(&leaves(6));
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{Digest, exceeds_depth, leaf_hash, node_hash};
+use ark_ff::PrimeField;
+use std::collections::BTreeMap;
+use sum_check::transcript::HashTrait;
+
+/// Compressed opening of several leaves: the sibling hashes the verifier
+/// cannot derive itself, layer by layer from the leaves up, and left to
+/// right within a layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiProof {
+    pub siblings: Vec<Digest>,
+}
+
+pub(crate) fn open(layers: &[Vec<Digest>], indices: &[usize]) -> MultiProof {
+    assert!(!indices.is_empty(), "need at least one leaf to open");
+    let mut known: Vec<usize> = indices.to_vec();
+    known.sort_unstable();
+    known.dedup();
+    assert!(
+        *known.last().unwrap() < layers[0].len(),
+        "leaf index out of range"
+    );
+
+    let mut siblings = vec![];
+    for layer in &layers[..layers.len() - 1] {
+        let mut next = Vec::with_capacity(known.len());
+        let mut i = 0;
+        while i < known.len() {
+            let index = known[i];
+            if index.is_multiple_of(2) && known.get(i + 1) == Some(&(index + 1)) {
+                i += 2;
+            } else {
+                siblings.push(layer[index ^ 1].clone());
+                i += 1;
+            }
+            next.push(index / 2);
+        }
+        known = next;
+    }
+    MultiProof { siblings }
+}
+
+/// Checks that each `leaves[i]` sits at `indices[i]` in the tree of the given
+/// depth under `root`. Repeated indices must carry the same leaf.
+pub fn verify_multi_proof<H: HashTrait + Default, F: PrimeField>(
+    root: &[u8],
+    depth: usize,
+    indices: &[usize],
+    leaves: &[Vec<F>],
+    proof: &MultiProof,
+) -> bool {
+    if indices.len() != leaves.len() || depth >= usize::BITS as usize {
+        return false;
+    }
+
+    let mut opened = BTreeMap::new();
+    for (&index, leaf) in indices.iter().zip(leaves) {
+        if exceeds_depth(index, depth) {
+            return false;
+        }
+        let hash = leaf_hash::<H, F>(leaf);
+        if opened
+            .insert(index, hash.clone())
+            .is_some_and(|other| other != hash)
+        {
+            return false;
+        }
+    }
+
+    let mut nodes: Vec<(usize, Digest)> = opened.into_iter().collect();
+    let mut siblings = proof.siblings.iter();
+    for _ in 0..depth {
+        let mut next = Vec::with_capacity(nodes.len());
+        let mut i = 0;
+        while i < nodes.len() {
+            let (index, hash) = &nodes[i];
+            let parent = match nodes.get(i + 1) {
+                Some((right, right_hash)) if index.is_multiple_of(2) && *right == index + 1 => {
+                    i += 2;
+                    node_hash::<H>(hash, right_hash)
+                }
+                _ => {
+                    let Some(sibling) = siblings.next() else {
+                        return false;
+                    };
+                    i += 1;
+                    if index.is_multiple_of(2) {
+                        node_hash::<H>(hash, sibling)
+                    } else {
+                        node_hash::<H>(sibling, hash)
+                    }
+                }
+            };
+            next.push((index / 2, parent));
+        }
+        nodes = next;
+    }
+
+    siblings.next().is_none() && nodes.len() == 1 && nodes[0].1 == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MerkleTree;
+    use crate::tests::leaves;
+    use sha3::Keccak256;
+
+    fn open_and_verify(num_leaves: usize, indices: &[usize]) -> MultiProof {
+        let leaves = leaves(num_leaves);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        let proof = tree.open_multi(indices);
+        let opened: Vec<_> = indices.iter().map(|&i| leaves[i].clone()).collect();
+        assert!(verify_multi_proof::<Keccak256, _>(
+            &tree.root(),
+            tree.depth(),
+            indices,
+            &opened,
+            &proof
+        ));
+        proof
+    }
+
+    #[test]
+    fn test_multi_proof_verifies() {
+        open_and_verify(16, &[3]);
+        open_and_verify(16, &[0, 5, 9, 15]);
+        open_and_verify(16, &[15, 2, 2, 7]);
+        open_and_verify(1, &[0]);
+    }
+
+    #[test]
+    fn test_multi_proof_is_compressed() {
+        // a single leaf needs the full path
+        assert_eq!(open_and_verify(16, &[6]).siblings.len(), 4);
+        // siblings share everything above the first layer
+        assert_eq!(open_and_verify(16, &[6, 7]).siblings.len(), 3);
+        // a whole subtree of four leaves costs two hashes
+        assert_eq!(open_and_verify(16, &[8, 9, 10, 11]).siblings.len(), 2);
+        // opening everything needs no hashes at all
+        let all: Vec<usize> = (0..16).collect();
+        assert!(open_and_verify(16, &all).siblings.is_empty());
+    }
+
+    #[test]
+    fn test_tampered_multi_proof_fails() {
+        let leaves = leaves(16);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        let indices = [1, 4, 13];
+        let opened: Vec<_> = indices.iter().map(|&i| leaves[i].clone()).collect();
+        let proof = tree.open_multi(&indices);
+        let verify = |indices: &[usize], opened: &[Vec<_>], proof: &MultiProof| {
+            verify_multi_proof::<Keccak256, _>(&tree.root(), tree.depth(), indices, opened, proof)
+        };
+
+        let mut wrong_leaf = opened.clone();
+        wrong_leaf[1][0] += ark_bn254::Fr::from(1u64);
+        assert!(!verify(&indices, &wrong_leaf, &proof));
+
+        assert!(!verify(&[1, 4, 12], &opened, &proof));
+
+        let mut extra = proof.clone();
+        extra.siblings.push(tree.root());
+        assert!(!verify(&indices, &opened, &extra));
+
+        let mut short = proof;
+        short.siblings.pop();
+        assert!(!verify(&indices, &opened, &short));
+    }
+
+    #[test]
+    fn test_oversized_depth_fails() {
+        let leaves = leaves(8);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        let proof = tree.open_multi(&[2]);
+        for depth in [64, 65, usize::MAX] {
+            assert!(!verify_multi_proof::<Keccak256, _>(
+                &tree.root(),
+                depth,
+                &[2],
+                &[leaves[2].clone()],
+                &proof
+            ));
+            assert!(!verify_multi_proof::<Keccak256, ark_bn254::Fr>(
+                &tree.root(),
+                depth,
+                &[],
+                &[],
+                &proof
+            ));
+        }
+    }
+
+    #[test]
+    fn test_conflicting_duplicates_fail() {
+        let leaves = leaves(8);
+        let tree = MerkleTree::<Keccak256>::new(&leaves);
+        let proof = tree.open_multi(&[2]);
+        assert!(!verify_multi_proof::<Keccak256, _>(
+            &tree.root(),
+            tree.depth(),
+            &[2, 2],
+            &[leaves[2].clone(), leaves[3].clone()],
+            &proof
+        ));
+    }
+}
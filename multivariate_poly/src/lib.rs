@@ -1,4 +1,6 @@
+pub mod notation;
 pub mod product_poly;
+mod serialization;
 pub mod sum_poly;
 
 use ark_ff::{BigInteger, PrimeField};
@@ -0,0 +1,184 @@
+//! Multilinear polynomials written over named variables, like `2ab + 3c`.
+//!
+//! Variables are lowercase letters, with `a` the first variable (the most
+//! significant bit of an index into the evaluation table), and a monomial
+//! names each of its variables at most once. The number of variables is set
+//! by the last letter used, so `b` alone has two. Parsing accepts at most
+//! [`MAX_PARSED_VARIABLES`] of them, so a single letter cannot ask for a huge
+//! evaluation table. Signs, coefficients and `*` follow the univariate
+//! notation in `univariate_poly::notation`.
+//!
+//! Polynomials over more than 26 variables print with indexed names joined
+//! by `*`, like `3x1*x27`; those only display and do not parse back.
+
+use crate::MultilinearPolynomial;
+use ark_ff::PrimeField;
+use std::fmt;
+use std::str::FromStr;
+use univariate_poly::notation::{format_terms, parse_terms, ParsePolynomialError};
+
+/// Most variables [`FromStr`] accepts, the letters `a` to `t`.
+pub const MAX_PARSED_VARIABLES: usize = 20;
+
+impl<F: PrimeField> fmt::Display for MultilinearPolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.no_of_variables();
+        let names = variable_names(n);
+        let separator = if n <= 26 { "" } else { "*" };
+
+        // Möbius transform from the evaluation table to monomial coefficients
+        let mut coefficients = self.coefficients.clone();
+        coefficients.resize(1 << n, F::zero());
+        for bit in 0..n {
+            for i in 0..coefficients.len() {
+                if i & (1 << bit) != 0 {
+                    let lower = coefficients[i ^ (1 << bit)];
+                    coefficients[i] -= lower;
+                }
+            }
+        }
+
+        let mut terms: Vec<(usize, F, String)> = coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(mask, &coefficient)| {
+                let variables: Vec<&str> = (0..n)
+                    .filter(|k| mask & (1 << (n - 1 - k)) != 0)
+                    .map(|k| names[k].as_str())
+                    .collect();
+                (variables.len(), coefficient, variables.join(separator))
+            })
+            .collect();
+        // highest degree first, then alphabetically
+        terms.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.2.cmp(&b.2)));
+
+        let terms: Vec<(F, String)> = terms.into_iter().map(|(_, c, m)| (c, m)).collect();
+        f.write_str(&format_terms(&terms))
+    }
+}
+
+/// `a` to `z` for up to 26 variables, otherwise `x1` to `xn`.
+fn variable_names(n: usize) -> Vec<String> {
+    if n <= 26 {
+        (0..n)
+            .map(|k| ((b'a' + k as u8) as char).to_string())
+            .collect()
+    } else {
+        (1..=n).map(|k| format!("x{k}")).collect()
+    }
+}
+
+impl<F: PrimeField> FromStr for MultilinearPolynomial<F> {
+    type Err = ParsePolynomialError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut terms = vec![];
+        let mut n = 0;
+        for (coefficient, monomial) in parse_terms::<F>(text)? {
+            let mut variables = 0u32;
+            for name in monomial.chars().filter(|&name| name != '*') {
+                if !name.is_ascii_lowercase() {
+                    return Err(ParsePolynomialError(format!("bad variable {name:?}")));
+                }
+                let k = (name as u8 - b'a') as usize;
+                if k >= MAX_PARSED_VARIABLES {
+                    return Err(ParsePolynomialError(format!(
+                        "{name:?} exceeds the limit of {MAX_PARSED_VARIABLES} variables"
+                    )));
+                }
+                if variables & (1 << k) != 0 {
+                    return Err(ParsePolynomialError(format!(
+                        "{monomial:?} is not multilinear"
+                    )));
+                }
+                variables |= 1 << k;
+                n = n.max(k + 1);
+            }
+            terms.push((coefficient, variables));
+        }
+
+        // place each coefficient at its variables' index, then sum over
+        // subsets (the zeta transform) to get the evaluation table
+        let mut evaluations = vec![F::zero(); 1 << n];
+        for (coefficient, variables) in terms {
+            let mask: usize = (0..n)
+                .filter(|k| variables & (1 << k) != 0)
+                .map(|k| 1 << (n - 1 - k))
+                .sum();
+            evaluations[mask] += coefficient;
+        }
+        for bit in 0..n {
+            for i in 0..evaluations.len() {
+                if i & (1 << bit) != 0 {
+                    let lower = evaluations[i ^ (1 << bit)];
+                    evaluations[i] += lower;
+                }
+            }
+        }
+        Ok(Self::new(evaluations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn to_field(inputs: Vec<u64>) -> Vec<Fq> {
+        inputs.into_iter().map(Fq::from).collect()
+    }
+
+    #[test]
+    fn test_parse_to_evaluation_table() {
+        // 2ab + 3c at abc = 000, 001, ..., 111
+        let polynomial: MultilinearPolynomial<Fq> = "2ab + 3c".parse().unwrap();
+        assert_eq!(
+            polynomial,
+            MultilinearPolynomial::new(to_field(vec![0, 3, 0, 3, 0, 3, 2, 5]))
+        );
+        assert_eq!(
+            polynomial.evaluate(&to_field(vec![2, 5, 7])),
+            Fq::from(2 * 2 * 5 + 3 * 7)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let polynomial = MultilinearPolynomial::new(to_field(vec![0, 2, 0, 5]));
+        assert_eq!(polynomial.to_string(), "3ab + 2b");
+        assert_eq!(MultilinearPolynomial::<Fq>::zero().to_string(), "0");
+        assert_eq!(
+            MultilinearPolynomial::new(vec![Fq::from(1), -Fq::from(1)]).to_string(),
+            "-2a + 1"
+        );
+    }
+
+    #[test]
+    fn test_variable_names() {
+        assert_eq!(variable_names(3), ["a", "b", "c"]);
+        assert_eq!(variable_names(26)[25], "z");
+        let names = variable_names(27);
+        assert_eq!((names[0].as_str(), names[26].as_str()), ("x1", "x27"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for text in ["2ab + 3c", "-abcd + 7bd - a + 1", "e"] {
+            let polynomial: MultilinearPolynomial<Fq> = text.parse().unwrap();
+            assert_eq!(polynomial.to_string(), text);
+        }
+        let polynomial: MultilinearPolynomial<Fq> = "3 * b * a + ba - 2".parse().unwrap();
+        assert_eq!(polynomial.to_string(), "4ab - 2");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for text in ["", "2aa", "3x^2", "a + B", "a +", "u", "z", "a + 2z"] {
+            assert!(
+                text.parse::<MultilinearPolynomial<Fq>>().is_err(),
+                "{text:?} should not parse"
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! `CanonicalSerialize`/`CanonicalDeserialize` for [`MultilinearPolynomial`]:
+//! the number of variables as a little-endian `u64`, then the evaluation
+//! table as a length-prefixed vector of field elements. Deserializing with
+//! validation rejects a table whose length is not exactly `2^n` for the
+//! stored variable count `n`.
+//!
+//! With the `serde` feature the same bytes back `Serialize`/`Deserialize`.
+
+use crate::MultilinearPolynomial;
+use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+
+impl<F: PrimeField> CanonicalSerialize for MultilinearPolynomial<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        (self.no_of_variables() as u64).serialize_with_mode(&mut writer, compress)?;
+        self.coefficients.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        8 + self.coefficients.serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for MultilinearPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if !self.coefficients.len().is_power_of_two() {
+            return Err(SerializationError::InvalidData);
+        }
+        self.coefficients.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for MultilinearPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let n = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+        let polynomial = Self::new(Vec::deserialize_with_mode(reader, compress, Validate::No)?);
+        if validate == Validate::Yes {
+            if n >= 64 || polynomial.coefficients.len() as u64 != 1 << n {
+                return Err(SerializationError::InvalidData);
+            }
+            polynomial.check()?;
+        }
+        Ok(polynomial)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F: PrimeField> serde::Serialize for MultilinearPolynomial<F> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = vec![];
+        self.serialize_compressed(&mut bytes)
+            .map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: PrimeField> serde::Deserialize<'de> for MultilinearPolynomial<F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: Vec<u8> = serde::Deserialize::deserialize(deserializer)?;
+        Self::deserialize_compressed(bytes.as_slice()).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn to_field(inputs: Vec<u64>) -> Vec<Fq> {
+        inputs.into_iter().map(Fq::from).collect()
+    }
+
+    fn to_bytes(polynomial: &MultilinearPolynomial<Fq>) -> Vec<u8> {
+        let mut bytes = vec![];
+        polynomial.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for polynomial in [
+            MultilinearPolynomial::new(to_field(vec![0, 3, 0, 3, 0, 3, 2, 5])),
+            MultilinearPolynomial::new(to_field(vec![1, 2])),
+            MultilinearPolynomial::zero(),
+        ] {
+            let bytes = to_bytes(&polynomial);
+            assert_eq!(bytes.len(), polynomial.serialized_size(Compress::Yes));
+            let decoded =
+                MultilinearPolynomial::<Fq>::deserialize_compressed(bytes.as_slice()).unwrap();
+            assert_eq!(decoded, polynomial);
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        let bytes = to_bytes(&MultilinearPolynomial::new(to_field(vec![1, 2, 3, 4])));
+        assert_eq!(bytes[..8], 2u64.to_le_bytes());
+        assert_eq!(bytes[8..16], 4u64.to_le_bytes());
+        assert_eq!(bytes.len(), 16 + 4 * 32);
+    }
+
+    #[test]
+    fn test_rejects_mismatched_variable_count() {
+        let mut bytes = vec![];
+        3u64.serialize_compressed(&mut bytes).unwrap();
+        to_field(vec![1, 2, 3, 4])
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert!(MultilinearPolynomial::<Fq>::deserialize_compressed(bytes.as_slice()).is_err());
+
+        // 3 entries would otherwise pass as a two-variable table
+        let mut bytes = vec![];
+        2u64.serialize_compressed(&mut bytes).unwrap();
+        to_field(vec![1, 2, 3])
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert!(MultilinearPolynomial::<Fq>::deserialize_compressed(bytes.as_slice()).is_err());
+
+        let mut bytes = vec![];
+        64u64.serialize_compressed(&mut bytes).unwrap();
+        to_field(vec![1]).serialize_compressed(&mut bytes).unwrap();
+        assert!(MultilinearPolynomial::<Fq>::deserialize_compressed(bytes.as_slice()).is_err());
+
+        // truncated input
+        let bytes = to_bytes(&MultilinearPolynomial::new(to_field(vec![1, 2])));
+        assert!(MultilinearPolynomial::<Fq>::deserialize_compressed(&bytes[..50]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let polynomial = MultilinearPolynomial::new(to_field(vec![9, 0, 4, 7]));
+        let json = serde_json::to_string(&polynomial).unwrap();
+        let decoded: MultilinearPolynomial<Fq> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, polynomial);
+    }
+}
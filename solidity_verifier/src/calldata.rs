@@ -0,0 +1,148 @@
+use crate::{selector, to_word};
+use ark_bn254::{Bn254, Fr};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use kzg::KZGProof;
+use sum_check::prover::Proof;
+
+pub const SUMCHECK_SIGNATURE: &str = "verify(uint256[],uint256,uint256[2][])";
+pub const KZG_SIGNATURE: &str = "verify(uint256[2],uint256,uint256[2][],uint256[])";
+
+/// ABI calldata for `SumcheckVerifier.verify(evaluations, claimedSum, roundPolys)`.
+pub fn encode_sumcheck_call<F: PrimeField>(evaluations: &[F], proof: &Proof<F>) -> Vec<u8> {
+    // head: offset(evaluations), claimedSum, offset(roundPolys)
+    let head_size = 3 * 32;
+    let evaluations_size = 32 * (1 + evaluations.len());
+
+    let mut words = vec![
+        usize_word(head_size),
+        to_word(proof.claimed_sum),
+        usize_word(head_size + evaluations_size),
+    ];
+
+    words.push(usize_word(evaluations.len()));
+    words.extend(evaluations.iter().map(|eval| to_word(*eval)));
+
+    words.push(usize_word(proof.round_polys.len()));
+    for round_poly in &proof.round_polys {
+        words.push(to_word(round_poly[0]));
+        words.push(to_word(round_poly[1]));
+    }
+
+    with_selector(SUMCHECK_SIGNATURE, words)
+}
+
+/// ABI calldata for `KzgVerifier.verify(commitment, value, quotients, point)`.
+pub fn encode_kzg_call(proof: &KZGProof<Fr, Bn254>, point: &[Fr]) -> Vec<u8> {
+    // head: commitment[0], commitment[1], value, offset(quotients), offset(point)
+    let head_size = 5 * 32;
+    let quotients_size = 32 * (1 + 2 * proof.quotient_evals.len());
+
+    let commitment = proof.commitment.into_affine();
+    let mut words = vec![
+        to_word(commitment.x),
+        to_word(commitment.y),
+        to_word(proof.poly_opened),
+        usize_word(head_size),
+        usize_word(head_size + quotients_size),
+    ];
+
+    words.push(usize_word(proof.quotient_evals.len()));
+    for quotient in &proof.quotient_evals {
+        let quotient = quotient.into_affine();
+        words.push(to_word(quotient.x));
+        words.push(to_word(quotient.y));
+    }
+
+    words.push(usize_word(point.len()));
+    words.extend(point.iter().map(|coord| to_word(*coord)));
+
+    with_selector(KZG_SIGNATURE, words)
+}
+
+fn usize_word(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn with_selector(signature: &str, words: Vec<[u8; 32]>) -> Vec<u8> {
+    let mut calldata = selector(signature).to_vec();
+    calldata.extend(words.iter().flatten());
+    calldata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+    use kzg::{trusted_setup::TrustedSetup, KZG};
+    use multivariate_poly::MultilinearPolynomial;
+    use sum_check::prover::Prover;
+
+    fn word_at(calldata: &[u8], index: usize) -> &[u8] {
+        &calldata[4 + 32 * index..4 + 32 * (index + 1)]
+    }
+
+    fn word_as_usize(calldata: &[u8], index: usize) -> usize {
+        let word = word_at(calldata, index);
+        assert!(word[..24].iter().all(|&byte| byte == 0));
+        u64::from_be_bytes(word[24..].try_into().unwrap()) as usize
+    }
+
+    #[test]
+    fn test_encode_sumcheck_call() {
+        let evaluations: Vec<Fr> = [0, 0, 0, 3, 0, 0, 2, 5].into_iter().map(Fr::from).collect();
+        let proof = Prover::new(&evaluations, Fr::from(10)).prove();
+        let calldata = encode_sumcheck_call(&evaluations, &proof);
+
+        assert_eq!(calldata[..4], selector(SUMCHECK_SIGNATURE));
+        assert_eq!(calldata.len(), 4 + 32 * (3 + 1 + 8 + 1 + 2 * 3));
+        assert_eq!(word_at(&calldata, 1), to_word(Fr::from(10)));
+
+        let evaluations_offset = word_as_usize(&calldata, 0) / 32;
+        assert_eq!(word_as_usize(&calldata, evaluations_offset), 8);
+        assert_eq!(
+            word_at(&calldata, evaluations_offset + 4),
+            to_word(Fr::from(3))
+        );
+
+        let rounds_offset = word_as_usize(&calldata, 2) / 32;
+        assert_eq!(word_as_usize(&calldata, rounds_offset), 3);
+        assert_eq!(
+            word_at(&calldata, rounds_offset + 1),
+            to_word(proof.round_polys[0][0])
+        );
+        assert_eq!(
+            word_at(&calldata, rounds_offset + 6),
+            to_word(proof.round_polys[2][1])
+        );
+    }
+
+    #[test]
+    fn test_encode_kzg_call() {
+        let taus = vec![Fr::from(5), Fr::from(2), Fr::from(3)];
+        let setup = TrustedSetup::<Bn254>::initialize(&taus);
+        let values: Vec<Fr> = (1..=8).map(Fr::from).collect();
+        let kzg = KZG::init(MultilinearPolynomial::new(values), setup);
+        let point = vec![Fr::from(6), Fr::from(4), Fr::from(0)];
+        let proof = kzg.prove(&point);
+        let calldata = encode_kzg_call(&proof, &point);
+
+        assert_eq!(calldata[..4], selector(KZG_SIGNATURE));
+        assert_eq!(calldata.len(), 4 + 32 * (5 + 1 + 2 * 3 + 1 + 3));
+        assert_eq!(
+            word_at(&calldata, 0),
+            to_word(proof.commitment.into_affine().x)
+        );
+        assert_eq!(word_at(&calldata, 2), to_word(proof.poly_opened));
+
+        let quotients_offset = word_as_usize(&calldata, 3) / 32;
+        assert_eq!(word_as_usize(&calldata, quotients_offset), 3);
+
+        let point_offset = word_as_usize(&calldata, 4) / 32;
+        assert_eq!(word_as_usize(&calldata, point_offset), 3);
+        assert_eq!(word_at(&calldata, point_offset + 1), to_word(Fr::from(6)));
+        assert_eq!(word_at(&calldata, point_offset + 3), to_word(Fr::zero()));
+    }
+}
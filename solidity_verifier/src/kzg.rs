@@ -0,0 +1,303 @@
+use crate::{modulus_literal, to_hex_word, to_word};
+use ark_bn254::{Bn254, Fq, Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+use kzg::{trusted_setup::TrustedSetup, KZGProof};
+
+/// Emits a Solidity contract verifying `kzg` openings against `setup` on BN254.
+///
+/// `KZG::verify` pairs each quotient with `[τ_i - a_i]₂`, which needs G2
+/// arithmetic the EVM does not have. The contract moves the `a_i` terms into
+/// G1 instead and checks, with the `ecAdd`, `ecMul` and `ecPairing` precompiles,
+///
+/// `e(C - v·G1 + Σ a_i·Q_i, G2) · Π e(-Q_i, [τ_i]₂) == 1`.
+pub fn generate_kzg_verifier(setup: &TrustedSetup<Bn254>) -> String {
+    assert!(!setup.g2_taus.is_empty(), "requires at least one variable");
+
+    let g2 = encode_g2(G2Affine::generator());
+    let mut taus = String::new();
+    for (i, tau) in setup.g2_taus.iter().enumerate() {
+        let offset = 6 * (i + 1) + 2;
+        for (j, word) in encode_g2(tau.into_affine()).iter().enumerate() {
+            taus.push_str(&format!("        input[{}] = {};\n", offset + j, word));
+        }
+    }
+
+    KZG_TEMPLATE
+        .replace("{BASE_MODULUS}", &modulus_literal::<Fq>())
+        .replace("{SCALAR_MODULUS}", &modulus_literal::<Fr>())
+        .replace("{NUM_VARS}", &setup.g2_taus.len().to_string())
+        .replace("{G2_X_IM}", &g2[0])
+        .replace("{G2_X_RE}", &g2[1])
+        .replace("{G2_Y_IM}", &g2[2])
+        .replace("{G2_Y_RE}", &g2[3])
+        .replace("{TAUS}\n", &taus)
+}
+
+/// The exact `ecPairing` input the generated contract builds for `proof`.
+pub fn pairing_input(
+    setup: &TrustedSetup<Bn254>,
+    proof: &KZGProof<Fr, Bn254>,
+    point: &[Fr],
+) -> Vec<u8> {
+    assert_eq!(point.len(), setup.g2_taus.len());
+    assert_eq!(proof.quotient_evals.len(), setup.g2_taus.len());
+
+    let mut lhs = proof.commitment - G1Projective::generator() * proof.poly_opened;
+    for (quotient, coord) in proof.quotient_evals.iter().zip(point.iter()) {
+        lhs += *quotient * coord;
+    }
+
+    let mut input = encode_g1(lhs.into_affine());
+    input.extend(encode_g2_bytes(G2Affine::generator()));
+    for (quotient, tau) in proof.quotient_evals.iter().zip(setup.g2_taus.iter()) {
+        input.extend(encode_g1((-*quotient).into_affine()));
+        input.extend(encode_g2_bytes(tau.into_affine()));
+    }
+    input
+}
+
+/// EIP-196 encoding of a G1 point: `x || y`, with the identity as `(0, 0)`.
+pub fn encode_g1(point: G1Affine) -> Vec<u8> {
+    let (x, y) = point.xy().unwrap_or((Fq::from(0), Fq::from(0)));
+    [to_word(x), to_word(y)].concat()
+}
+
+/// EIP-197 encoding of a G2 point: imaginary part before the real part.
+fn encode_g2(point: G2Affine) -> [String; 4] {
+    let (x, y) = point.xy().expect("setup points are never the identity");
+    [
+        to_hex_word(x.c1),
+        to_hex_word(x.c0),
+        to_hex_word(y.c1),
+        to_hex_word(y.c0),
+    ]
+}
+
+fn encode_g2_bytes(point: G2Affine) -> Vec<u8> {
+    let (x, y) = point.xy().expect("setup points are never the identity");
+    [to_word(x.c1), to_word(x.c0), to_word(y.c1), to_word(y.c0)].concat()
+}
+
+const KZG_TEMPLATE: &str = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+/// @title KzgVerifier
+/// @notice Verifies multilinear KZG openings produced by `kzg::KZG` over BN254.
+/// @dev Generated by `solidity_verifier::kzg::generate_kzg_verifier`. Checks
+///      e(C - v*G1 + sum(a_i * Q_i), G2) * prod(e(-Q_i, [tau_i]_2)) == 1.
+contract KzgVerifier {
+    uint256 internal constant BASE_MODULUS = {BASE_MODULUS};
+    uint256 internal constant SCALAR_MODULUS = {SCALAR_MODULUS};
+    uint256 internal constant NUM_VARS = {NUM_VARS};
+
+    uint256 internal constant G2_X_IM = {G2_X_IM};
+    uint256 internal constant G2_X_RE = {G2_X_RE};
+    uint256 internal constant G2_Y_IM = {G2_Y_IM};
+    uint256 internal constant G2_Y_RE = {G2_Y_RE};
+
+    function verify(
+        uint256[2] calldata commitment,
+        uint256 value,
+        uint256[2][] calldata quotients,
+        uint256[] calldata point
+    ) external view returns (bool) {
+        if (quotients.length != NUM_VARS || point.length != NUM_VARS) {
+            return false;
+        }
+        if (value >= SCALAR_MODULUS) {
+            return false;
+        }
+
+        bool ok;
+        uint256[2] memory term;
+        (ok, term) = _ecMul([uint256(1), uint256(2)], (SCALAR_MODULUS - value) % SCALAR_MODULUS);
+        if (!ok) {
+            return false;
+        }
+        uint256[2] memory lhs;
+        (ok, lhs) = _ecAdd([commitment[0], commitment[1]], term);
+        if (!ok) {
+            return false;
+        }
+
+        uint256[] memory input = new uint256[](6 * (NUM_VARS + 1));
+        for (uint256 i = 0; i < NUM_VARS; i++) {
+            uint256[2] memory quotient = [quotients[i][0], quotients[i][1]];
+            if (quotient[1] >= BASE_MODULUS || point[i] >= SCALAR_MODULUS) {
+                return false;
+            }
+
+            (ok, term) = _ecMul(quotient, point[i]);
+            if (!ok) {
+                return false;
+            }
+            (ok, lhs) = _ecAdd(lhs, term);
+            if (!ok) {
+                return false;
+            }
+
+            input[6 * (i + 1)] = quotient[0];
+            input[6 * (i + 1) + 1] = quotient[1] == 0 ? 0 : BASE_MODULUS - quotient[1];
+        }
+
+        input[0] = lhs[0];
+        input[1] = lhs[1];
+        input[2] = G2_X_IM;
+        input[3] = G2_X_RE;
+        input[4] = G2_Y_IM;
+        input[5] = G2_Y_RE;
+        _writeTaus(input);
+
+        uint256[1] memory out;
+        assembly {
+            ok := staticcall(gas(), 0x08, add(input, 0x20), mul(mload(input), 0x20), out, 0x20)
+        }
+        return ok && out[0] == 1;
+    }
+
+    /// @dev [tau_i]_2 from the trusted setup, in EIP-197 order.
+    function _writeTaus(uint256[] memory input) private pure {
+{TAUS}
+    }
+
+    function _ecAdd(uint256[2] memory a, uint256[2] memory b)
+        private
+        view
+        returns (bool ok, uint256[2] memory r)
+    {
+        uint256[4] memory input = [a[0], a[1], b[0], b[1]];
+        assembly {
+            ok := staticcall(gas(), 0x06, input, 0x80, r, 0x40)
+        }
+    }
+
+    function _ecMul(uint256[2] memory p, uint256 s)
+        private
+        view
+        returns (bool ok, uint256[2] memory r)
+    {
+        uint256[3] memory input = [p[0], p[1], s];
+        assembly {
+            ok := staticcall(gas(), 0x07, input, 0x60, r, 0x40)
+        }
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calldata::encode_kzg_call,
+        tests::{call_verify, deploy, execute},
+    };
+    use kzg::KZG;
+    use multivariate_poly::MultilinearPolynomial;
+
+    /// Forwards the calldata to the `ecPairing` precompile and returns `out || success`.
+    fn pairing_bytecode() -> Vec<u8> {
+        vec![
+            0x36, 0x60, 0x00, 0x60, 0x00, 0x37, // CALLDATACOPY(0, 0, CALLDATASIZE)
+            0x60, 0x20, 0x60, 0x00, 0x36, 0x60, 0x00, 0x60, 0x08, 0x5a, // args
+            0xfa, // STATICCALL
+            0x60, 0x20, 0x52, // MSTORE(0x20, success)
+            0x60, 0x40, 0x60, 0x00, 0xf3, // RETURN(0, 0x40)
+        ]
+    }
+
+    fn setup_and_proof(
+        values: Vec<u64>,
+        point: &[Fr],
+    ) -> (TrustedSetup<Bn254>, KZGProof<Fr, Bn254>) {
+        let taus = vec![Fr::from(5), Fr::from(2), Fr::from(3)];
+        let poly = MultilinearPolynomial::new(values.into_iter().map(Fr::from).collect());
+        let proof =
+            KZG::init(poly, TrustedSetup::<Bn254>::initialize(&taus)).prove(&point.to_vec());
+        (TrustedSetup::<Bn254>::initialize(&taus), proof)
+    }
+
+    #[test]
+    fn test_generate_kzg_verifier() {
+        let point = vec![Fr::from(6), Fr::from(4), Fr::from(0)];
+        let (setup, _) = setup_and_proof(vec![1, 2, 3, 4, 5, 6, 7, 8], &point);
+        let source = generate_kzg_verifier(&setup);
+
+        assert!(source.contains("uint256 internal constant NUM_VARS = 3;"));
+        assert!(source.contains(&modulus_literal::<Fq>()));
+        assert!(source.contains(&modulus_literal::<Fr>()));
+        // three G2 points of four words each, at pairs 1..=3
+        assert!(source.contains("        input[8] = 0x"));
+        assert!(source.contains("        input[23] = 0x"));
+        assert!(!source.contains("input[24]"));
+        assert!(!source.contains("{TAUS}"));
+    }
+
+    #[test]
+    fn test_evm_pairing_accepts_valid_opening() {
+        let point = vec![Fr::from(6), Fr::from(4), Fr::from(0)];
+        let (setup, proof) = setup_and_proof(vec![0, 4, 0, 4, 0, 4, 3, 7], &point);
+
+        let output = execute(pairing_bytecode(), pairing_input(&setup, &proof, &point));
+        assert_eq!(output[63], 1, "precompile call failed");
+        assert_eq!(output[31], 1);
+    }
+
+    #[test]
+    fn test_evm_pairing_rejects_wrong_value() {
+        let point = vec![Fr::from(6), Fr::from(4), Fr::from(0)];
+        let (setup, mut proof) = setup_and_proof(vec![1, 2, 3, 4, 5, 6, 7, 8], &point);
+        proof.poly_opened += Fr::from(1);
+
+        let output = execute(pairing_bytecode(), pairing_input(&setup, &proof, &point));
+        assert_eq!(output[63], 1, "precompile call failed");
+        assert_eq!(output[31], 0);
+    }
+
+    #[test]
+    fn test_evm_pairing_rejects_wrong_point() {
+        let point = vec![Fr::from(6), Fr::from(4), Fr::from(0)];
+        let (setup, proof) = setup_and_proof(vec![1, 2, 3, 4, 5, 6, 7, 8], &point);
+        let other_point = vec![Fr::from(6), Fr::from(4), Fr::from(1)];
+
+        let output = execute(
+            pairing_bytecode(),
+            pairing_input(&setup, &proof, &other_point),
+        );
+        assert_eq!(output[63], 1, "precompile call failed");
+        assert_eq!(output[31], 0);
+    }
+
+    #[test]
+    #[ignore = "needs solc on PATH or named by SOLC"]
+    fn test_generated_verifier_in_evm() {
+        let point = vec![Fr::from(6), Fr::from(4), Fr::from(0)];
+        let (setup, proof) = setup_and_proof(vec![0, 4, 0, 4, 0, 4, 3, 7], &point);
+        let verifier = deploy(&generate_kzg_verifier(&setup), "KzgVerifier");
+        assert!(call_verify(&verifier, encode_kzg_call(&proof, &point)));
+
+        let wrong_value = KZGProof {
+            commitment: proof.commitment,
+            poly_opened: proof.poly_opened + Fr::from(1),
+            quotient_evals: proof.quotient_evals.clone(),
+        };
+        assert!(!call_verify(
+            &verifier,
+            encode_kzg_call(&wrong_value, &point)
+        ));
+
+        let other_point = vec![Fr::from(6), Fr::from(4), Fr::from(1)];
+        assert!(!call_verify(
+            &verifier,
+            encode_kzg_call(&proof, &other_point)
+        ));
+
+        let missing_quotient = KZGProof {
+            quotient_evals: proof.quotient_evals[..2].to_vec(),
+            ..proof
+        };
+        assert!(!call_verify(
+            &verifier,
+            encode_kzg_call(&missing_quotient, &point[..2])
+        ));
+    }
+}
@@ -0,0 +1,172 @@
+pub mod calldata;
+pub mod kzg;
+pub mod sumcheck;
+
+use ark_ff::{BigInteger, PrimeField};
+use sha3::{Digest, Keccak256};
+
+/// Big-endian 32-byte word of a field element, the layout used both by the
+/// EVM and by `convert_to_bytes` for 256-bit fields.
+pub fn to_word<F: PrimeField>(value: F) -> [u8; 32] {
+    let bytes = value.into_bigint().to_bytes_be();
+    assert!(
+        bytes.len() <= 32,
+        "field element does not fit in an EVM word"
+    );
+
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+    word
+}
+
+/// Formats a field element as a `0x`-prefixed hex literal for Solidity source.
+pub fn to_hex_word<F: PrimeField>(value: F) -> String {
+    hex_literal(&to_word(value))
+}
+
+/// Solidity literal for the modulus of `F`.
+pub fn modulus_literal<F: PrimeField>() -> String {
+    let bytes = F::MODULUS.to_bytes_be();
+    assert!(bytes.len() <= 32, "modulus does not fit in an EVM word");
+
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+    hex_literal(&word)
+}
+
+/// First four bytes of the Keccak256 hash of a function signature.
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = Keccak256::digest(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn hex_literal(bytes: &[u8]) -> String {
+    let digits: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{}", digits)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use revm::{
+        db::InMemoryDB,
+        primitives::{address, hex, AccountInfo, Bytecode, TxKind, U256},
+        Evm,
+    };
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    /// Deploys `code` at a fixed address of an in-memory EVM and calls it with `calldata`.
+    pub(crate) fn execute(code: Vec<u8>, calldata: Vec<u8>) -> Vec<u8> {
+        let code = Bytecode::new_raw(code.into());
+        let code_hash = code.hash_slow();
+        let to_addr = address!("00000000000000000000000000000000000000ff");
+
+        let mut evm = Evm::builder()
+            .with_db(InMemoryDB::default())
+            .modify_db(|db| {
+                db.insert_account_info(to_addr, AccountInfo::new(U256::ZERO, 0, code_hash, code))
+            })
+            .modify_tx_env(|tx| {
+                tx.transact_to = TxKind::Call(to_addr);
+                tx.data = calldata.into();
+                tx.gas_limit = 30_000_000;
+            })
+            .build();
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success(), "EVM execution failed: {:?}", result);
+        result.into_output().unwrap().to_vec()
+    }
+
+    /// Compiles `source` with `solc` (or the binary named by `SOLC`), runs the
+    /// creation code of `contract` and returns the deployed runtime code.
+    ///
+    /// Panics when the compiler cannot be run, so the `#[ignore]`d tests
+    /// that call this fail instead of passing without a verifier.
+    pub(crate) fn deploy(source: &str, contract: &str) -> Vec<u8> {
+        let solc = std::env::var("SOLC").unwrap_or_else(|_| "solc".into());
+        let mut child = Command::new(&solc)
+            .args(["--bin", "--optimize", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap_or_else(|error| panic!("failed to start {solc:?}: {error}"));
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(source.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        assert!(
+            output.status.success(),
+            "solc failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // ======= <stdin>:Name =======
+        // Binary:
+        // 6080...
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let header = format!(":{contract} =======");
+        let mut lines = stdout.lines().skip_while(|line| !line.ends_with(&header));
+        let creation = lines
+            .nth(2)
+            .unwrap_or_else(|| panic!("no bytecode for {contract} in solc output"));
+        let creation = hex::decode(creation.trim()).unwrap();
+
+        let mut evm = Evm::builder()
+            .with_db(InMemoryDB::default())
+            .modify_tx_env(|tx| {
+                tx.transact_to = TxKind::Create;
+                tx.data = creation.into();
+                tx.gas_limit = 30_000_000;
+            })
+            .build();
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success(), "deployment failed: {:?}", result);
+        result.into_output().unwrap().to_vec()
+    }
+
+    /// Calls a deployed verifier and decodes its `bool` return value.
+    pub(crate) fn call_verify(runtime: &[u8], calldata: Vec<u8>) -> bool {
+        let output = execute(runtime.to_vec(), calldata);
+        assert_eq!(output.len(), 32, "verify must return a single bool");
+        assert!(output[..31].iter().all(|&byte| byte == 0));
+        output[31] == 1
+    }
+
+    #[test]
+    fn test_to_word() {
+        let word = to_word(Fr::from(258));
+        assert_eq!(word[30..], [1, 2]);
+        assert!(word[..30].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn test_to_hex_word() {
+        let literal = to_hex_word(Fr::from(255));
+        assert_eq!(literal.len(), 66);
+        assert!(literal.ends_with("00ff"));
+    }
+
+    #[test]
+    fn test_selector() {
+        // well known ERC-20 selector
+        assert_eq!(
+            selector("transfer(address,uint256)"),
+            [0xa9, 0x05, 0x9c, 0xbb]
+        );
+    }
+
+    #[test]
+    fn test_execute_returns_output() {
+        // PUSH1 0x2a PUSH1 0 MSTORE PUSH1 0x20 PUSH1 0 RETURN
+        let code = vec![0x60, 0x2a, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+        let output = execute(code, vec![]);
+        assert_eq!(output[31], 0x2a);
+    }
+}
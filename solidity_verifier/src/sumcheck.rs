@@ -0,0 +1,250 @@
+use crate::modulus_literal;
+use ark_ff::{BigInteger, PrimeField};
+
+/// Emits a Solidity contract that replays `sum_check::verifier::Verify` for
+/// multilinear polynomials in `no_of_variables` variables.
+///
+/// The contract rebuilds the prover's Keccak256 transcript byte for byte.
+/// Polynomials are absorbed in their canonical encoding (variable count and
+/// table length as little-endian `u64`s, then 32-byte little-endian
+/// elements), the claimed sum as a big-endian word, and each challenge is
+/// the hash read as a little-endian integer reduced mod p.
+pub fn generate_sumcheck_verifier<F: PrimeField>(no_of_variables: usize) -> String {
+    assert_eq!(
+        F::MODULUS.to_bytes_be().len(),
+        32,
+        "transcript encoding requires a 256-bit field"
+    );
+    assert!(no_of_variables > 0, "requires at least one variable");
+
+    SUMCHECK_TEMPLATE
+        .replace("{MODULUS}", &modulus_literal::<F>())
+        .replace("{NUM_VARS}", &no_of_variables.to_string())
+}
+
+const SUMCHECK_TEMPLATE: &str = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+/// @title SumcheckVerifier
+/// @notice Verifies sum-check proofs produced by `sum_check::prover::Prover`.
+/// @dev Generated by `solidity_verifier::sumcheck::generate_sumcheck_verifier`.
+contract SumcheckVerifier {
+    uint256 internal constant MODULUS = {MODULUS};
+    uint256 internal constant NUM_VARS = {NUM_VARS};
+
+    function verify(
+        uint256[] calldata evaluations,
+        uint256 claimedSum,
+        uint256[2][] calldata roundPolys
+    ) external pure returns (bool) {
+        if (evaluations.length != 1 << NUM_VARS || roundPolys.length != NUM_VARS) {
+            return false;
+        }
+        if (claimedSum >= MODULUS) {
+            return false;
+        }
+
+        bytes memory transcript = abi.encodePacked(_u64(NUM_VARS), _u64(evaluations.length));
+        for (uint256 i = 0; i < evaluations.length; i++) {
+            if (evaluations[i] >= MODULUS) {
+                return false;
+            }
+            transcript = abi.encodePacked(transcript, _reverse(evaluations[i]));
+        }
+        transcript = abi.encodePacked(transcript, claimedSum);
+        uint256[] memory challenges = new uint256[](NUM_VARS);
+        uint256 sum = claimedSum;
+
+        for (uint256 i = 0; i < NUM_VARS; i++) {
+            uint256 r0 = roundPolys[i][0];
+            uint256 r1 = roundPolys[i][1];
+            if (r0 >= MODULUS || r1 >= MODULUS) {
+                return false;
+            }
+            if (addmod(r0, r1, MODULUS) != sum) {
+                return false;
+            }
+
+            transcript = abi.encodePacked(transcript, _u64(1), _u64(2), _reverse(r0), _reverse(r1));
+            uint256 challenge = _squeeze(transcript);
+            sum = addmod(r0, mulmod(challenge, addmod(r1, MODULUS - r0, MODULUS), MODULUS), MODULUS);
+            challenges[i] = challenge;
+        }
+
+        return sum == _evaluate(evaluations, challenges);
+    }
+
+    /// @dev Keccak256 of the transcript read as a little-endian integer mod p.
+    function _squeeze(bytes memory transcript) private pure returns (uint256) {
+        return _reverse(uint256(keccak256(transcript))) % MODULUS;
+    }
+
+    /// @dev Reverses the byte order of a word.
+    function _reverse(uint256 word) private pure returns (uint256 reversed) {
+        for (uint256 i = 0; i < 32; i++) {
+            reversed |= ((word >> (8 * i)) & 0xff) << (8 * (31 - i));
+        }
+    }
+
+    /// @dev `n` as the 8 little-endian bytes of a `u64` length prefix.
+    function _u64(uint256 n) private pure returns (bytes8) {
+        return bytes8(bytes32(_reverse(n)));
+    }
+
+    /// @dev Folds the evaluation table on the most significant variable first,
+    ///      matching `MultilinearPolynomial::evaluate`.
+    function _evaluate(uint256[] calldata evaluations, uint256[] memory point)
+        private
+        pure
+        returns (uint256)
+    {
+        uint256[] memory table = evaluations;
+        uint256 len = table.length;
+        for (uint256 i = 0; i < point.length; i++) {
+            len /= 2;
+            for (uint256 j = 0; j < len; j++) {
+                uint256 diff = addmod(table[j + len], MODULUS - table[j], MODULUS);
+                table[j] = addmod(table[j], mulmod(point[i], diff, MODULUS), MODULUS);
+            }
+        }
+        return table[0];
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calldata::encode_sumcheck_call,
+        tests::{call_verify, deploy, execute},
+        to_word,
+    };
+    use ark_bn254::Fq;
+    use multivariate_poly::MultilinearPolynomial;
+    use sha3::Keccak256;
+    use sum_check::{prover::Prover, transcript::Transcript, verifier::Verify};
+
+    /// Bytecode of `_squeeze` over the whole calldata: keccak, byte reversal, mod p.
+    fn squeeze_bytecode<F: PrimeField>() -> Vec<u8> {
+        // CALLDATASIZE PUSH1 0 PUSH1 0 CALLDATACOPY CALLDATASIZE PUSH1 0 KECCAK256 PUSH1 0
+        let mut code = vec![
+            0x36, 0x60, 0x00, 0x60, 0x00, 0x37, 0x36, 0x60, 0x00, 0x20, 0x60, 0x00,
+        ];
+        for i in 0..32u8 {
+            // DUP2 PUSH1 8i SHR PUSH1 0xff AND PUSH1 8(31-i) SHL OR
+            code.extend([0x81, 0x60, 8 * i, 0x1c, 0x60, 0xff, 0x16]);
+            code.extend([0x60, 8 * (31 - i), 0x1b, 0x17]);
+        }
+        // PUSH32 MODULUS SWAP1 MOD
+        code.push(0x7f);
+        code.extend(to_word_bytes(F::MODULUS.to_bytes_be()));
+        code.extend([0x90, 0x06]);
+        // PUSH1 0 MSTORE PUSH1 0x20 PUSH1 0 RETURN
+        code.extend([0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3]);
+        code
+    }
+
+    fn to_word_bytes(bytes: Vec<u8>) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[32 - bytes.len()..].copy_from_slice(&bytes);
+        word
+    }
+
+    fn le_word(value: Fq) -> [u8; 32] {
+        let mut word = to_word(value);
+        word.reverse();
+        word
+    }
+
+    fn to_field(input: Vec<u64>) -> Vec<Fq> {
+        input.into_iter().map(Fq::from).collect()
+    }
+
+    #[test]
+    fn test_generate_sumcheck_verifier() {
+        let source = generate_sumcheck_verifier::<Fq>(3);
+        assert!(source.contains("uint256 internal constant NUM_VARS = 3;"));
+        assert!(source.contains(&modulus_literal::<Fq>()));
+        assert!(!source.contains("{MODULUS}"));
+        assert!(!source.contains("{NUM_VARS}"));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires at least one variable")]
+    fn test_generate_sumcheck_verifier_without_variables() {
+        generate_sumcheck_verifier::<Fq>(0);
+    }
+
+    #[test]
+    fn test_evm_squeeze_matches_transcript() {
+        let evaluations = to_field(vec![0, 0, 0, 3, 0, 0, 2, 5]);
+        let proof = Prover::new(&evaluations, Fq::from(10)).prove();
+        assert!(Verify::new(&evaluations).verify(proof.clone()));
+
+        // _u64(NUM_VARS), _u64(evaluations.length), each _reverse(evaluation), claimedSum
+        let mut packed: Vec<u8> = [3u64.to_le_bytes(), 8u64.to_le_bytes()].concat();
+        packed.extend(evaluations.iter().flat_map(|eval| le_word(*eval)));
+        packed.extend(to_word(proof.claimed_sum));
+
+        // absorbed exactly as `Verify::verify` does
+        let mut transcript = Transcript::<Keccak256, Fq>::init(Keccak256::default());
+        transcript.absorb_serializable(&MultilinearPolynomial::new(evaluations.clone()));
+        transcript.absorb(&proof.claimed_sum.into_bigint().to_bytes_be());
+
+        for round_poly in &proof.round_polys {
+            // _u64(1), _u64(2), _reverse(r0), _reverse(r1)
+            packed.extend(1u64.to_le_bytes());
+            packed.extend(2u64.to_le_bytes());
+            packed.extend(le_word(round_poly[0]));
+            packed.extend(le_word(round_poly[1]));
+            transcript.absorb_serializable(&MultilinearPolynomial::new(round_poly.to_vec()));
+
+            let expected = to_word(transcript.squeeze());
+            let output = execute(squeeze_bytecode::<Fq>(), packed.clone());
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs solc on PATH or named by SOLC"]
+    fn test_generated_verifier_in_evm() {
+        let verifier = deploy(&generate_sumcheck_verifier::<Fq>(3), "SumcheckVerifier");
+        let evaluations = to_field(vec![0, 0, 0, 3, 0, 0, 2, 5]);
+        let proof = Prover::new(&evaluations, Fq::from(10)).prove();
+        assert!(call_verify(
+            &verifier,
+            encode_sumcheck_call(&evaluations, &proof)
+        ));
+
+        let mut wrong_sum = proof.clone();
+        wrong_sum.claimed_sum += Fq::from(1);
+        assert!(!call_verify(
+            &verifier,
+            encode_sumcheck_call(&evaluations, &wrong_sum)
+        ));
+
+        // keeps every round sum consistent but changes the challenges
+        let mut shifted = proof.clone();
+        shifted.round_polys[1][0] += Fq::from(1);
+        shifted.round_polys[1][1] -= Fq::from(1);
+        assert!(!call_verify(
+            &verifier,
+            encode_sumcheck_call(&evaluations, &shifted)
+        ));
+
+        let mut other_evaluations = evaluations.clone();
+        other_evaluations[0] += Fq::from(1);
+        assert!(!call_verify(
+            &verifier,
+            encode_sumcheck_call(&other_evaluations, &proof)
+        ));
+
+        let mut missing_round = proof.clone();
+        missing_round.round_polys.pop();
+        assert!(!call_verify(
+            &verifier,
+            encode_sumcheck_call(&evaluations, &missing_round)
+        ));
+    }
+}
@@ -30,8 +30,7 @@ impl<F: PrimeField> Prover<F> {
         let mut round_polys = Vec::new();
 
         // append poly eval coefficients
-        self.transcripts
-            .absorb(self.initial_poly.convert_to_bytes().as_slice());
+        self.transcripts.absorb_serializable(&self.initial_poly);
         self.transcripts
             .absorb(self.claimed_sum.into_bigint().to_bytes_be().as_slice());
 
@@ -40,8 +39,7 @@ impl<F: PrimeField> Prover<F> {
         for _ in 0..self.initial_poly.no_of_variables() {
             let round_poly_coeffs = split_and_sum(&poly.coefficients);
             let round_poly = MultilinearPolynomial::new(round_poly_coeffs.to_vec());
-            self.transcripts
-                .absorb(round_poly.convert_to_bytes().as_slice());
+            self.transcripts.absorb_serializable(&round_poly);
             round_polys.push(round_poly_coeffs);
 
             let challenge: F = self.transcripts.squeeze();
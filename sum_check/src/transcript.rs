@@ -3,6 +3,8 @@
 // use multivariate_poly::MultilinearPolynomial;
 
 use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 use std::marker::PhantomData;
 
@@ -24,6 +26,16 @@ impl<K: HashTrait, F: PrimeField> Transcript<K, F> {
         self.hash_function.append(data);
     }
 
+    /// Absorbs the compressed canonical encoding of `value`, which for
+    /// polynomials carries their length, so adjacent ones stay distinct.
+    pub fn absorb_serializable<T: CanonicalSerialize>(&mut self, value: &T) {
+        let mut bytes = Vec::with_capacity(value.compressed_size());
+        value
+            .serialize_compressed(&mut bytes)
+            .expect("serializing into a Vec cannot fail");
+        self.absorb(&bytes);
+    }
+
     pub fn squeeze(&self) -> F {
         let hash_output = self.hash_function.generate_hash();
         F::from_le_bytes_mod_order(&hash_output)
@@ -45,6 +57,16 @@ impl HashTrait for Keccak256 {
     }
 }
 
+impl HashTrait for Sha256 {
+    fn append(&mut self, data: &[u8]) {
+        self.update(data);
+    }
+
+    fn generate_hash(&self) -> Vec<u8> {
+        self.clone().finalize().to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +79,12 @@ mod tests {
         let challenge: Fq = transcript.squeeze();
         dbg!("{}", challenge);
     }
+
+    #[test]
+    fn test_sha256_hash_trait() {
+        let mut hasher = Sha256::new();
+        hasher.append(b"ab");
+        hasher.append(b"c");
+        assert_eq!(hasher.generate_hash()[..4], [0xba, 0x78, 0x16, 0xbf]);
+    }
 }
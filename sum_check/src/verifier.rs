@@ -21,8 +21,7 @@ impl<F: PrimeField> Verify<F> {
         let mut challenges = vec![];
 
         // self.initial_poly = initial_poly.clone();
-        self.transcript
-            .absorb(self.original_poly.convert_to_bytes().as_slice());
+        self.transcript.absorb_serializable(&self.original_poly);
         self.transcript
             .absorb(proof.claimed_sum.into_bigint().to_bytes_be().as_slice());
         let mut claimed_sum = proof.claimed_sum;
@@ -34,8 +33,7 @@ impl<F: PrimeField> Verify<F> {
                 return false;
             }
             let converted_poly = MultilinearPolynomial::new(round_poly.to_vec());
-            self.transcript
-                .absorb(converted_poly.convert_to_bytes().as_slice());
+            self.transcript.absorb_serializable(&converted_poly);
             println!("round poly sum: {:?} ", round_poly.iter().sum::<F>());
             println!("claimed sum: {}", claimed_sum);
             let challenge: F = self.transcript.squeeze();
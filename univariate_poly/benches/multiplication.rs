@@ -0,0 +1,65 @@
+//! Compares schoolbook, Karatsuba and FFT products across sizes, to place
+//! `KARATSUBA_MUL_THRESHOLD`, `KARATSUBA_FFT_MUL_THRESHOLD` and
+//! `FFT_MUL_THRESHOLD` where the curves cross. Below
+//! `KARATSUBA_MUL_THRESHOLD` the `karatsuba` case is schoolbook, so lower
+//! that constant to compare the two there.
+//!
+//! Run with `cargo bench -p univariate_poly --bench multiplication`.
+
+use ark_bn254::{Fq, Fr};
+use ark_ff::PrimeField;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use univariate_poly::UnivariatePolynomial;
+
+fn random_coefficients<F: PrimeField>(n: usize) -> Vec<F> {
+    let mut rng = ark_std::test_rng();
+    (0..n).map(|_| F::rand(&mut rng)).collect()
+}
+
+fn bench_algorithms(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multiply/bn254_fr");
+    for n in [
+        8, 12, 16, 24, 32, 40, 48, 64, 80, 96, 112, 128, 192, 256, 512,
+    ] {
+        let a = random_coefficients::<Fr>(n);
+        let b = random_coefficients::<Fr>(n);
+
+        group.bench_with_input(BenchmarkId::new("schoolbook", n), &n, |bench, _| {
+            bench.iter(|| UnivariatePolynomial::multiply_polynomials(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("karatsuba", n), &n, |bench, _| {
+            bench.iter(|| {
+                UnivariatePolynomial::karatsuba_multiply_polynomials(black_box(&a), black_box(&b))
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("fft", n), &n, |bench, _| {
+            bench.iter(|| {
+                UnivariatePolynomial::fft_multiply_polynomials(black_box(&a), black_box(&b))
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_without_fft(c: &mut Criterion) {
+    // BN254's base field has no large 2-adic subgroup, so only the first two
+    // algorithms apply
+    let mut group = c.benchmark_group("multiply/bn254_fq");
+    for n in [32, 128, 512] {
+        let a = random_coefficients::<Fq>(n);
+        let b = random_coefficients::<Fq>(n);
+
+        group.bench_with_input(BenchmarkId::new("schoolbook", n), &n, |bench, _| {
+            bench.iter(|| UnivariatePolynomial::multiply_polynomials(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("karatsuba", n), &n, |bench, _| {
+            bench.iter(|| {
+                UnivariatePolynomial::karatsuba_multiply_polynomials(black_box(&a), black_box(&b))
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_algorithms, bench_without_fft);
+criterion_main!(benches);
@@ -0,0 +1,172 @@
+use crate::UnivariatePolynomial;
+use ark_ff::{batch_inversion, PrimeField};
+
+/// A fixed set of distinct points with precomputed barycentric weights
+/// `wⱼ = 1/Πₖ≠ⱼ (xⱼ - xₖ)`, so a polynomial of degree below `n` can stay
+/// in evaluation form and still be evaluated anywhere in `O(n)`:
+///
+/// `f(x) = l(x) · Σ wⱼ·f(xⱼ)/(x - xⱼ)` with `l(x) = Π (x - xⱼ)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarycentricDomain<F: PrimeField> {
+    points: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: PrimeField> BarycentricDomain<F> {
+    /// Weights for arbitrary points, in `O(n²)` with a single inversion.
+    pub fn new(points: Vec<F>) -> Self {
+        assert!(!points.is_empty(), "need at least one point");
+
+        let mut weights: Vec<F> = points
+            .iter()
+            .enumerate()
+            .map(|(j, &x_j)| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k != j)
+                    .map(|(_, &x_k)| x_j - x_k)
+                    .product()
+            })
+            .collect();
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "interpolation points must be distinct"
+        );
+        batch_inversion(&mut weights);
+
+        Self { points, weights }
+    }
+
+    /// The points `0, 1, ..., n - 1` used for sum-check round polynomials,
+    /// whose weights `(-1)^(n-1-j) / (j!·(n-1-j)!)` need no products.
+    pub fn integers(n: usize) -> Self {
+        assert!(n > 0, "need at least one point");
+
+        let mut inverse_factorials = Vec::with_capacity(n);
+        let mut factorial = F::one();
+        for i in 0..n {
+            if i > 0 {
+                factorial *= F::from(i as u64);
+            }
+            inverse_factorials.push(factorial);
+        }
+        batch_inversion(&mut inverse_factorials);
+
+        let weights = (0..n)
+            .map(|j| {
+                let weight = inverse_factorials[j] * inverse_factorials[n - 1 - j];
+                if (n - 1 - j) % 2 == 1 {
+                    -weight
+                } else {
+                    weight
+                }
+            })
+            .collect();
+
+        Self {
+            points: (0..n).map(|i| F::from(i as u64)).collect(),
+            weights,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// Evaluates at `x` the polynomial taking `values[j]` at point `j`, with
+    /// one batch inversion of the differences `x - xⱼ`.
+    pub fn evaluate(&self, values: &[F], x: F) -> F {
+        assert_eq!(values.len(), self.len(), "expected one value per point");
+        if let Some(j) = self.points.iter().position(|&x_j| x_j == x) {
+            return values[j];
+        }
+
+        let mut differences: Vec<F> = self.points.iter().map(|&x_j| x - x_j).collect();
+        let vanishing: F = differences.iter().product();
+        batch_inversion(&mut differences);
+
+        let sum: F = values
+            .iter()
+            .zip(&self.weights)
+            .zip(&differences)
+            .map(|((&y, &w), &d)| y * w * d)
+            .sum();
+        vanishing * sum
+    }
+
+    /// Coefficients of the polynomial taking `values` on the points.
+    pub fn interpolate(&self, values: &[F]) -> UnivariatePolynomial<F> {
+        assert_eq!(values.len(), self.len(), "expected one value per point");
+        UnivariatePolynomial::interpolate(
+            self.points
+                .iter()
+                .copied()
+                .zip(values.iter().copied())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::UniformRand;
+
+    #[test]
+    fn test_integer_weights_match_general_weights() {
+        for n in 1..8 {
+            let integers = BarycentricDomain::<Fr>::integers(n);
+            let general = BarycentricDomain::new((0..n as u64).map(Fr::from).collect());
+            assert_eq!(integers, general);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_matches_coefficient_form() {
+        let mut rng = ark_std::test_rng();
+        let polynomial = UnivariatePolynomial::new((0..6).map(|_| Fr::rand(&mut rng)).collect());
+        let domain = BarycentricDomain::new((0..6).map(|_| Fr::rand(&mut rng)).collect());
+        let values: Vec<Fr> = domain
+            .points()
+            .iter()
+            .map(|&x| polynomial.evaluate(x))
+            .collect();
+
+        for _ in 0..5 {
+            let x = Fr::rand(&mut rng);
+            assert_eq!(domain.evaluate(&values, x), polynomial.evaluate(x));
+        }
+        // on the points themselves
+        assert_eq!(domain.evaluate(&values, domain.points()[3]), values[3]);
+        assert_eq!(domain.interpolate(&values), polynomial);
+    }
+
+    #[test]
+    fn test_quadratic_round_polynomial() {
+        // 3x² - x + 2 from its values at 0, 1, 2
+        let domain = BarycentricDomain::integers(3);
+        let values = [Fr::from(2), Fr::from(4), Fr::from(12)];
+        assert_eq!(domain.evaluate(&values, Fr::from(5)), Fr::from(72));
+        assert_eq!(domain.evaluate(&values, -Fr::from(1)), Fr::from(6));
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation points must be distinct")]
+    fn test_repeated_points() {
+        BarycentricDomain::new(vec![Fr::from(1), Fr::from(2), Fr::from(2)]);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::UnivariatePolynomial;
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+
+/// `f(X, Y) = Σ cᵢⱼ·XⁱYʲ`, with `coefficients[i][j] = cᵢⱼ` in a rectangular
+/// table, as used for bivariate secret sharing: party `i` holds the row
+/// `f(i, Y)` and can cross-check it with party `j` through `f(i, j)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BivariatePolynomial<F: PrimeField> {
+    pub coefficients: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> BivariatePolynomial<F> {
+    pub fn new(coefficients: Vec<Vec<F>>) -> Self {
+        assert!(!coefficients.is_empty(), "need at least one row");
+        let width = coefficients[0].len();
+        assert!(
+            coefficients.iter().all(|row| row.len() == width),
+            "coefficient rows must have equal length"
+        );
+        Self { coefficients }
+    }
+
+    /// Random coefficients of degree at most `degree_x` in `X` and
+    /// `degree_y` in `Y`, except for `f(0, 0) = constant`.
+    pub fn rand<R: Rng>(degree_x: usize, degree_y: usize, constant: F, rng: &mut R) -> Self {
+        let mut coefficients: Vec<Vec<F>> = (0..=degree_x)
+            .map(|_| (0..=degree_y).map(|_| F::rand(rng)).collect())
+            .collect();
+        coefficients[0][0] = constant;
+        Self::new(coefficients)
+    }
+
+    /// A random symmetric polynomial, `f(X, Y) = f(Y, X)`, of degree at most
+    /// `degree` in each variable with `f(0, 0) = constant`.
+    pub fn rand_symmetric<R: Rng>(degree: usize, constant: F, rng: &mut R) -> Self {
+        // upper[i][k] is the coefficient of XⁱYⁱ⁺ᵏ and of Xⁱ⁺ᵏYⁱ
+        let upper: Vec<Vec<F>> = (0..=degree)
+            .map(|i| (i..=degree).map(|_| F::rand(rng)).collect())
+            .collect();
+        let mut coefficients: Vec<Vec<F>> = (0..=degree)
+            .map(|i| {
+                (0..=degree)
+                    .map(|j| {
+                        if j >= i {
+                            upper[i][j - i]
+                        } else {
+                            upper[j][i - j]
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        coefficients[0][0] = constant;
+        Self::new(coefficients)
+    }
+
+    pub fn degree_x(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    pub fn degree_y(&self) -> usize {
+        self.coefficients[0].len().saturating_sub(1)
+    }
+
+    pub fn constant_term(&self) -> F {
+        self.coefficients[0].first().copied().unwrap_or(F::zero())
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        let n = self.coefficients.len().max(self.coefficients[0].len());
+        let at = |i: usize, j: usize| {
+            self.coefficients
+                .get(i)
+                .and_then(|row| row.get(j))
+                .copied()
+                .unwrap_or(F::zero())
+        };
+        (0..n).all(|i| (i + 1..n).all(|j| at(i, j) == at(j, i)))
+    }
+
+    pub fn evaluate(&self, x: F, y: F) -> F {
+        self.partial_evaluate_x(x).evaluate(y)
+    }
+
+    /// `f(x, Y)` as a polynomial in `Y`.
+    pub fn partial_evaluate_x(&self, x: F) -> UnivariatePolynomial<F> {
+        let mut result = vec![F::zero(); self.coefficients[0].len()];
+        for row in self.coefficients.iter().rev() {
+            for (acc, &c) in result.iter_mut().zip(row) {
+                *acc = *acc * x + c;
+            }
+        }
+        UnivariatePolynomial::new(result)
+    }
+
+    /// `f(X, y)` as a polynomial in `X`.
+    pub fn partial_evaluate_y(&self, y: F) -> UnivariatePolynomial<F> {
+        UnivariatePolynomial::new(
+            self.coefficients
+                .iter()
+                .map(|row| UnivariatePolynomial::new(row.clone()).evaluate(y))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    fn table(rows: &[&[i64]]) -> BivariatePolynomial<Fr> {
+        BivariatePolynomial::new(
+            rows.iter()
+                .map(|row| row.iter().map(|&c| Fr::from(c)).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_evaluate() {
+        // 1 + 2Y + 3X + 4XY
+        let f = table(&[&[1, 2], &[3, 4]]);
+        assert_eq!(
+            f.evaluate(Fr::from(5), Fr::from(7)),
+            Fr::from(1 + 14 + 15 + 140)
+        );
+        assert_eq!(f.degree_x(), 1);
+        assert_eq!(f.degree_y(), 1);
+        assert_eq!(f.constant_term(), Fr::from(1));
+    }
+
+    #[test]
+    fn test_partial_evaluation() {
+        // 1 + 2Y + 3Y² + X(4 + 5Y + 6Y²)
+        let f = table(&[&[1, 2, 3], &[4, 5, 6]]);
+        let row = f.partial_evaluate_x(Fr::from(2));
+        assert_eq!(
+            row,
+            UnivariatePolynomial::new(vec![9.into(), 12.into(), 15.into()])
+        );
+        let column = f.partial_evaluate_y(Fr::from(3));
+        assert_eq!(
+            column,
+            UnivariatePolynomial::new(vec![34.into(), 73.into()])
+        );
+
+        let (x, y) = (Fr::from(11), Fr::from(13));
+        assert_eq!(f.partial_evaluate_x(x).evaluate(y), f.evaluate(x, y));
+        assert_eq!(f.partial_evaluate_y(y).evaluate(x), f.evaluate(x, y));
+    }
+
+    #[test]
+    fn test_rand_keeps_constant_term() {
+        let mut rng = ark_std::test_rng();
+        let secret = Fr::from(42);
+        let f = BivariatePolynomial::rand(3, 5, secret, &mut rng);
+        assert_eq!((f.degree_x(), f.degree_y()), (3, 5));
+        assert_eq!(f.evaluate(Fr::from(0), Fr::from(0)), secret);
+        assert!(!f.is_symmetric());
+    }
+
+    #[test]
+    fn test_symmetric_shares_cross_check() {
+        let mut rng = ark_std::test_rng();
+        let secret = Fr::from(7);
+        let threshold = 3;
+        let f = BivariatePolynomial::rand_symmetric(threshold - 1, secret, &mut rng);
+        assert!(f.is_symmetric());
+
+        // party i holds f(i, Y); parties i and j agree on f(i, j) = f(j, i)
+        let shares: Vec<UnivariatePolynomial<Fr>> = (1..=5u64)
+            .map(|i| f.partial_evaluate_x(Fr::from(i)))
+            .collect();
+        for i in 1..=5u64 {
+            for j in 1..=5u64 {
+                assert_eq!(
+                    shares[i as usize - 1].evaluate(Fr::from(j)),
+                    shares[j as usize - 1].evaluate(Fr::from(i))
+                );
+            }
+        }
+
+        // any `threshold` of the values f(i, 0) recover the secret
+        let points: Vec<(Fr, Fr)> = (2..=4u64)
+            .map(|i| (Fr::from(i), shares[i as usize - 1].evaluate(Fr::from(0))))
+            .collect();
+        assert_eq!(
+            UnivariatePolynomial::interpolate(points).evaluate(Fr::from(0)),
+            secret
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "coefficient rows must have equal length")]
+    fn test_ragged_rows() {
+        table(&[&[1, 2], &[3]]);
+    }
+}
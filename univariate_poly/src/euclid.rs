@@ -0,0 +1,211 @@
+use crate::UnivariatePolynomial;
+use ark_ff::PrimeField;
+
+impl<F: PrimeField> UnivariatePolynomial<F> {
+    /// Formal derivative `Σ i·cᵢ·Xⁱ⁻¹`.
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * F::from(i as u64))
+                .collect(),
+        )
+        .trimmed()
+    }
+
+    /// `self(inner(X))`, by Horner's rule over polynomials.
+    pub fn compose(&self, inner: &Self) -> Self {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Self::zero(), |acc, &coeff| {
+                &(&acc * inner) + &Self::new(vec![coeff])
+            })
+    }
+
+    /// The power series inverse `g` with `self·g ≡ 1 mod Xⁿ`, by Newton
+    /// iteration `g ← g·(2 - self·g)`, doubling the precision each step.
+    /// Uses [`fft::division::inverse_mod_xn`] when the field has a subgroup
+    /// for the `2n`-coefficient products, and generic products otherwise.
+    ///
+    /// Panics if the constant term is zero.
+    pub fn inverse_mod_xn(&self, n: usize) -> Self {
+        let constant = self.coefficients.first().copied().unwrap_or(F::zero());
+        assert!(!constant.is_zero(), "constant term must be invertible");
+        if Self::supports_fft(2 * n) {
+            return Self::new(fft::division::inverse_mod_xn(&self.coefficients, n)).trimmed();
+        }
+
+        let mut inverse = Self::new(vec![constant.inverse().unwrap()]);
+        let mut precision = 1;
+        while precision < n {
+            precision = (2 * precision).min(n);
+
+            let truncated =
+                Self::new(self.coefficients[..self.coefficients.len().min(precision)].to_vec());
+            let mut error = -&(&truncated * &inverse);
+            error.coefficients.resize(precision, F::zero());
+            error.coefficients[0] += F::from(2u64);
+
+            inverse = &inverse * &error;
+            inverse.coefficients.truncate(precision);
+        }
+
+        inverse.coefficients.resize(n, F::zero());
+        inverse.trimmed()
+    }
+
+    /// The monic greatest common divisor, zero when both inputs are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone().trimmed(), other.clone().trimmed());
+        while !b.is_zero() {
+            let remainder = a.div_rem(&b).1;
+            a = b;
+            b = remainder;
+        }
+        a.into_monic()
+    }
+
+    /// Extended Euclid: `(g, s, t)` with `s·self + t·other = g` and `g` the
+    /// monic gcd. The Bezout coefficients satisfy `deg s < deg other` and
+    /// `deg t < deg self` whenever both inputs are non-constant.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let one = Self::new(vec![F::one()]);
+        let (mut r0, mut r1) = (self.clone().trimmed(), other.clone().trimmed());
+        let (mut s0, mut s1) = (one.clone(), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), one);
+
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1);
+            let s2 = &s0 - &(&quotient * &s1);
+            let t2 = &t0 - &(&quotient * &t1);
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s2);
+            (t0, t1) = (t1, t2);
+        }
+
+        if r0.is_zero() {
+            return (Self::zero(), Self::zero(), Self::zero());
+        }
+        let lead_inv = r0.leading_coefficient().inverse().unwrap();
+        (r0 * lead_inv, s0 * lead_inv, t0 * lead_inv)
+    }
+
+    /// Scales to leading coefficient one; the zero polynomial stays zero.
+    pub(crate) fn into_monic(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        let lead_inv = self.leading_coefficient().inverse().unwrap();
+        self * lead_inv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::UniformRand;
+    use ark_std::rand::RngCore;
+
+    fn poly(coefficients: &[i64]) -> UnivariatePolynomial<Fr> {
+        UnivariatePolynomial::new(coefficients.iter().map(|&c| Fr::from(c)).collect())
+    }
+
+    fn random_poly(rng: &mut impl RngCore, len: usize) -> UnivariatePolynomial<Fr> {
+        UnivariatePolynomial::new((0..len).map(|_| Fr::rand(rng)).collect())
+    }
+
+    #[test]
+    fn test_derivative() {
+        // d/dx (1 + 2x + 3x² + 4x³) = 2 + 6x + 12x²
+        assert_eq!(poly(&[1, 2, 3, 4]).derivative(), poly(&[2, 6, 12]));
+        assert!(poly(&[5]).derivative().is_zero());
+        assert!(UnivariatePolynomial::<Fr>::zero().derivative().is_zero());
+    }
+
+    #[test]
+    fn test_compose() {
+        // (x² + 1)∘(x + 2) = x² + 4x + 5
+        assert_eq!(poly(&[1, 0, 1]).compose(&poly(&[2, 1])), poly(&[5, 4, 1]));
+
+        let mut rng = ark_std::test_rng();
+        let f = random_poly(&mut rng, 7);
+        let g = random_poly(&mut rng, 4);
+        let x = Fr::from(11);
+        assert_eq!(f.compose(&g).evaluate(x), f.evaluate(g.evaluate(x)));
+        assert_eq!(f.compose(&g).degree(), 18);
+    }
+
+    #[test]
+    fn test_inverse_mod_xn() {
+        // 1/(1 - x) = 1 + x + x² + ...
+        assert_eq!(poly(&[1, -1]).inverse_mod_xn(5), poly(&[1, 1, 1, 1, 1]));
+
+        let mut rng = ark_std::test_rng();
+        for n in [1, 2, 9, 100] {
+            let f = random_poly(&mut rng, 70);
+            let mut product = &f * &f.inverse_mod_xn(n);
+            product.coefficients.truncate(n);
+            assert_eq!(product, poly(&[1]));
+        }
+    }
+
+    #[test]
+    fn test_inverse_mod_xn_without_fft() {
+        // BN254's base field has 2-adicity 1, so only the generic loop applies
+        assert!(!UnivariatePolynomial::<Fq>::supports_fft(2 * 100));
+        let mut rng = ark_std::test_rng();
+        let f = UnivariatePolynomial::new((0..70).map(|_| Fq::rand(&mut rng)).collect());
+        let mut product = &f * &f.inverse_mod_xn(100);
+        product.coefficients.truncate(100);
+        assert_eq!(product, UnivariatePolynomial::new(vec![Fq::from(1)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "constant term must be invertible")]
+    fn test_inverse_of_non_unit() {
+        poly(&[0, 1]).inverse_mod_xn(4);
+    }
+
+    #[test]
+    fn test_gcd() {
+        // (x - 1)(x - 2) and (x - 1)(x + 3) share x - 1
+        let a = poly(&[2, -3, 1]);
+        let b = poly(&[-3, 2, 1]);
+        assert_eq!(a.gcd(&b), poly(&[-1, 1]));
+        assert_eq!(a.gcd(&poly(&[1, 1])), poly(&[1]));
+        assert_eq!(
+            (&a * Fr::from(7)).gcd(&UnivariatePolynomial::zero()),
+            a.clone()
+        );
+        assert!(UnivariatePolynomial::<Fr>::zero()
+            .gcd(&UnivariatePolynomial::zero())
+            .is_zero());
+    }
+
+    #[test]
+    fn test_xgcd_bezout_identity() {
+        let mut rng = ark_std::test_rng();
+        let common = random_poly(&mut rng, 4);
+        let a = &common * &random_poly(&mut rng, 9);
+        let b = &common * &random_poly(&mut rng, 6);
+
+        let (g, s, t) = a.xgcd(&b);
+        assert_eq!(g, common.gcd(&common));
+        assert_eq!(&(&s * &a) + &(&t * &b), g);
+        assert!(s.degree() < b.degree());
+        assert!(t.degree() < a.degree());
+    }
+
+    #[test]
+    fn test_xgcd_over_base_field() {
+        let a = UnivariatePolynomial::new(vec![Fq::from(1), Fq::from(0), Fq::from(1)]);
+        let b = UnivariatePolynomial::new(vec![Fq::from(3), Fq::from(1)]);
+        let (g, s, t) = a.xgcd(&b);
+        assert_eq!(g, UnivariatePolynomial::new(vec![Fq::from(1)]));
+        assert_eq!(&(&s * &a) + &(&t * &b), g);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::UnivariatePolynomial;
+use ark_ff::{BigInteger, PrimeField};
+
+impl<F: PrimeField> UnivariatePolynomial<F> {
+    /// Every root in `F`, repeated by multiplicity and in ascending order.
+    ///
+    /// The distinct roots are the linear factors of `gcd(self, Xᵖ - X)`,
+    /// which Cantor–Zassenhaus splitting pulls apart.
+    ///
+    /// Panics on the zero polynomial.
+    pub fn roots(&self) -> Vec<F> {
+        assert!(!self.is_zero(), "the zero polynomial vanishes everywhere");
+        let monic = self.clone().into_monic();
+        if monic.degree() == 0 {
+            return vec![];
+        }
+
+        let x = Self::new(vec![F::zero(), F::one()]);
+        let frobenius = x.pow_mod(F::MODULUS.as_ref(), &monic);
+        let linear_part = monic.gcd(&(&frobenius - &x));
+
+        let mut roots = vec![];
+        let mut rng = SPLIT_SEED;
+        for factor in linear_part.split_equal_degree(1, &mut rng) {
+            let root = -factor.coefficients[0];
+            let mut remaining = monic.clone();
+            loop {
+                let (quotient, remainder) = remaining.div_rem(&factor);
+                if !remainder.is_zero() {
+                    break;
+                }
+                roots.push(root);
+                remaining = quotient;
+            }
+        }
+        roots.sort();
+        roots
+    }
+
+    /// Monic irreducible factors with their multiplicities, sorted by degree
+    /// and then by coefficients, so that
+    /// `self = leading_coefficient() · Π fᵢ^eᵢ`.
+    ///
+    /// Runs square-free, distinct-degree and then equal-degree
+    /// (Cantor–Zassenhaus) factorisation. The splitting polynomials come
+    /// from a fixed seed, so the output is reproducible.
+    ///
+    /// Panics on the zero polynomial.
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "the zero polynomial has no factorisation");
+        let mut rng = SPLIT_SEED;
+        let mut factors = vec![];
+        for (square_free, multiplicity) in self.clone().into_monic().square_free_factors() {
+            for (product, degree) in square_free.distinct_degree_factors() {
+                for factor in product.split_equal_degree(degree, &mut rng) {
+                    factors.push((factor, multiplicity));
+                }
+            }
+        }
+        factors.sort_by(|(a, _), (b, _)| {
+            (a.degree(), &a.coefficients).cmp(&(b.degree(), &b.coefficients))
+        });
+        factors
+    }
+
+    /// `self^exponent mod modulus`, with the exponent as little-endian
+    /// 64-bit limbs.
+    fn pow_mod(&self, exponent: &[u64], modulus: &Self) -> Self {
+        let mut result = Self::new(vec![F::one()]).div_rem(modulus).1;
+        let base = self.div_rem(modulus).1;
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = (&result * &result).div_rem(modulus).1;
+                if (limb >> bit) & 1 == 1 {
+                    result = (&result * &base).div_rem(modulus).1;
+                }
+            }
+        }
+        result
+    }
+
+    /// Yun's algorithm on a monic polynomial: pairwise coprime square-free
+    /// `(gᵢ, i)` with `self = Π gᵢⁱ`. A leftover with zero derivative is a
+    /// `p`-th power and is handled by taking its `p`-th root.
+    fn square_free_factors(&self) -> Vec<(Self, usize)> {
+        let one = Self::new(vec![F::one()]);
+        let mut factors = vec![];
+
+        let mut c = self.gcd(&self.derivative());
+        let mut w = self.div_rem(&c).0;
+        let mut i = 1;
+        while w != one {
+            let y = w.gcd(&c);
+            let z = w.div_rem(&y).0;
+            if z != one {
+                factors.push((z, i));
+            }
+            i += 1;
+            c = c.div_rem(&y).0;
+            w = y;
+        }
+
+        if c != one {
+            // the derivative of c vanishes, so c(X) = h(Xᵖ) = h(X)ᵖ
+            let p = F::MODULUS.as_ref()[0] as usize;
+            let root = Self::new(c.coefficients.iter().step_by(p).copied().collect());
+            for (factor, multiplicity) in root.square_free_factors() {
+                factors.push((factor, multiplicity * p));
+            }
+        }
+        factors
+    }
+
+    /// Splits a monic square-free polynomial into `(product, d)`, where
+    /// `product` collects every irreducible factor of degree `d`.
+    fn distinct_degree_factors(&self) -> Vec<(Self, usize)> {
+        let x = Self::new(vec![F::zero(), F::one()]);
+        let mut remaining = self.clone();
+        let mut frobenius = x.clone();
+        let mut factors = vec![];
+
+        let mut degree = 1;
+        while remaining.degree() >= 2 * degree {
+            // frobenius = X^(pᵈ) mod remaining
+            frobenius = frobenius.pow_mod(F::MODULUS.as_ref(), &remaining);
+            let product = remaining.gcd(&(&frobenius - &x));
+            if product.degree() > 0 {
+                remaining = remaining.div_rem(&product).0;
+                frobenius = frobenius.div_rem(&remaining).1;
+                factors.push((product, degree));
+            }
+            degree += 1;
+        }
+        if remaining.degree() > 0 {
+            let degree = remaining.degree();
+            factors.push((remaining, degree));
+        }
+        factors
+    }
+
+    /// Cantor–Zassenhaus: splits a monic product of distinct irreducibles of
+    /// degree `degree` with `gcd(self, a^((pᵈ-1)/2) - 1)` for pseudo-random `a`.
+    fn split_equal_degree(&self, degree: usize, rng: &mut u64) -> Vec<Self> {
+        if self.degree() == 0 {
+            return vec![];
+        }
+        if self.degree() == degree {
+            return vec![self.clone()];
+        }
+        assert!(
+            F::MODULUS.is_odd(),
+            "equal-degree splitting needs an odd characteristic"
+        );
+
+        let one = Self::new(vec![F::one()]);
+        loop {
+            let a = Self::new(
+                (0..self.degree())
+                    .map(|_| F::from(next_random(rng)))
+                    .collect(),
+            );
+
+            // (pᵈ-1)/2 = (1 + p + ... + pᵈ⁻¹)·(p-1)/2, so raise the norm
+            // a·aᵖ·...·a^(pᵈ⁻¹) to (p-1)/2
+            let mut norm = a.clone();
+            let mut conjugate = a;
+            for _ in 1..degree {
+                conjugate = conjugate.pow_mod(F::MODULUS.as_ref(), self);
+                norm = (&norm * &conjugate).div_rem(self).1;
+            }
+            let candidate = &norm.pow_mod(F::MODULUS_MINUS_ONE_DIV_TWO.as_ref(), self) - &one;
+
+            let divisor = self.gcd(&candidate);
+            if divisor.degree() > 0 && divisor.degree() < self.degree() {
+                let cofactor = self.div_rem(&divisor).0;
+                let mut factors = divisor.split_equal_degree(degree, rng);
+                factors.extend(cofactor.split_equal_degree(degree, rng));
+                return factors;
+            }
+        }
+    }
+}
+
+const SPLIT_SEED: u64 = 0x5eed;
+
+/// splitmix64, enough to pick splitting polynomials reproducibly.
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::{Field, Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "7"]
+    #[generator = "3"]
+    pub struct F7Config;
+    type F7 = Fp64<MontBackend<F7Config, 1>>;
+
+    fn poly(coefficients: &[i64]) -> UnivariatePolynomial<Fr> {
+        UnivariatePolynomial::new(coefficients.iter().map(|&c| Fr::from(c)).collect())
+    }
+
+    fn from_roots(roots: &[Fr]) -> UnivariatePolynomial<Fr> {
+        roots.iter().fold(poly(&[1]), |acc, &r| {
+            &acc * &UnivariatePolynomial::new(vec![-r, Fr::from(1)])
+        })
+    }
+
+    #[test]
+    fn test_roots_of_characteristic_polynomial() {
+        // recover a set from Π (X - sᵢ)
+        let set: Vec<Fr> = [3u64, 17, 1 << 40, 99, 5].map(Fr::from).to_vec();
+        let mut expected = set.clone();
+        expected.sort();
+        assert_eq!(from_roots(&set).roots(), expected);
+    }
+
+    #[test]
+    fn test_roots_with_multiplicity() {
+        let multiset = [Fr::from(2), Fr::from(9), Fr::from(2), Fr::from(2)];
+        let polynomial = &from_roots(&multiset) * Fr::from(4);
+        assert_eq!(
+            polynomial.roots(),
+            vec![Fr::from(2), Fr::from(2), Fr::from(2), Fr::from(9)]
+        );
+    }
+
+    #[test]
+    fn test_roots_ignore_irreducible_factors() {
+        // X² - c has no roots when c is a non-residue
+        let mut c = 2;
+        while Fr::from(c).legendre().is_qr() {
+            c += 1;
+        }
+        let quadratic = UnivariatePolynomial::new(vec![-Fr::from(c), 0.into(), 1.into()]);
+        let polynomial = &quadratic * &from_roots(&[Fr::from(7)]);
+        assert_eq!(polynomial.roots(), vec![Fr::from(7)]);
+        assert!(quadratic.roots().is_empty());
+        assert!(poly(&[5]).roots().is_empty());
+    }
+
+    #[test]
+    fn test_factor_round_trip() {
+        let mut c = 2;
+        while Fr::from(c).legendre().is_qr() {
+            c += 1;
+        }
+        let irreducible_quadratic =
+            UnivariatePolynomial::new(vec![-Fr::from(c), 0.into(), 1.into()]);
+        let cubic = poly(&[1, 1, 0, 1]);
+        let polynomial = &(&(&irreducible_quadratic * &irreducible_quadratic)
+            * &from_roots(&[Fr::from(1), Fr::from(4), Fr::from(4)]))
+            * &(&cubic * Fr::from(3));
+
+        let factors = polynomial.factor();
+        let product = factors.iter().fold(poly(&[1]), |acc, (factor, e)| {
+            (0..*e).fold(acc, |acc, _| &acc * factor)
+        });
+        assert_eq!(&product * polynomial.leading_coefficient(), polynomial);
+        assert!(factors
+            .iter()
+            .all(|(f, _)| f.leading_coefficient() == Fr::from(1)));
+        assert!(factors.contains(&(irreducible_quadratic, 2)));
+        assert!(factors.contains(&(poly(&[-4, 1]), 2)));
+        assert!(factors.contains(&(poly(&[-1, 1]), 1)));
+    }
+
+    #[test]
+    fn test_factor_over_small_field() {
+        let f7 = |coefficients: &[u64]| {
+            UnivariatePolynomial::new(coefficients.iter().map(|&c| F7::from(c)).collect())
+        };
+        // X⁷ - X = Π (X - a) over F₇
+        let mut frobenius = vec![0; 8];
+        frobenius[1] = 6;
+        frobenius[7] = 1;
+        let roots = f7(&frobenius).roots();
+        assert_eq!(roots, (0..7).map(F7::from).collect::<Vec<_>>());
+
+        // (X² + 1)⁷ = X¹⁴ + 1 has a vanishing derivative
+        let mut seventh_power = vec![0; 15];
+        seventh_power[0] = 1;
+        seventh_power[14] = 1;
+        assert_eq!(f7(&seventh_power).factor(), vec![(f7(&[1, 0, 1]), 7)]);
+
+        // X³ + X + 1 is irreducible over F₇ and X⁴ + 1 splits into quadratics
+        assert_eq!(f7(&[1, 1, 0, 1]).factor(), vec![(f7(&[1, 1, 0, 1]), 1)]);
+        let quartic = f7(&[1, 0, 0, 0, 1]).factor();
+        assert_eq!(quartic.len(), 2);
+        assert!(quartic.iter().all(|(f, e)| f.degree() == 2 && *e == 1));
+    }
+}
@@ -1,23 +1,111 @@
 use ark_ff::{BigInteger, PrimeField};
+use fft::{division::divide_with_remainder, domain::EvaluationDomain};
 use std::cmp::max;
+use subproduct::SubproductTree;
+
+pub mod barycentric;
+pub mod bivariate;
+mod euclid;
+mod factor;
+pub mod notation;
+mod ops;
+mod serialization;
+pub mod sparse;
+pub mod subproduct;
+
+/// Below this many coefficients in the smaller factor, schoolbook
+/// multiplication beats Karatsuba's extra additions and allocations.
+///
+/// From `cargo bench -p univariate_poly --bench multiplication` over BN254
+/// `Fr` with equal-length factors, with this constant lowered so the
+/// `karatsuba` case splits at every size: the two are level at 8
+/// coefficients (2.6 µs each), and Karatsuba wins at 12 (5.6 µs against
+/// 6.6 µs) and 16 (9.4 µs against 12.8 µs).
+pub const KARATSUBA_MUL_THRESHOLD: usize = 16;
+
+/// From this many coefficients in the smaller factor, [`UnivariatePolynomial::multiply`]
+/// switches from Karatsuba to FFT products.
+///
+/// From the same bench: level at 64 coefficients (90 µs Karatsuba against
+/// 93 µs FFT), Karatsuba well ahead at 80 (129 µs against 229 µs, as the
+/// product pads to 256 points), level again at 96 (234 µs against 220 µs),
+/// and FFT ahead at 112 (317 µs against 194 µs) and 128 (379 µs against
+/// 210 µs).
+pub const KARATSUBA_FFT_MUL_THRESHOLD: usize = 96;
+
+pub use fft::ntt::FFT_MUL_THRESHOLD;
 
 #[derive(Debug, Clone)]
 pub struct UnivariatePolynomial<F: PrimeField> {
     pub coefficients: Vec<F>, //ascending degree
 }
 
+/// Polynomials are equal when their coefficients agree up to trailing zeros.
+impl<F: PrimeField> PartialEq for UnivariatePolynomial<F> {
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.trimmed_len();
+        len == other.trimmed_len() && self.coefficients[..len] == other.coefficients[..len]
+    }
+}
+
+impl<F: PrimeField> Eq for UnivariatePolynomial<F> {}
+
 impl<F: PrimeField> UnivariatePolynomial<F> {
     pub fn new(coefficients: Vec<F>) -> Self {
         Self { coefficients }
     }
 
+    /// The zero polynomial, with no coefficients.
+    pub fn zero() -> Self {
+        Self::new(vec![])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.trimmed_len() == 0
+    }
+
+    /// Drops trailing zero coefficients, so the zero polynomial has none.
+    pub fn trim(&mut self) {
+        self.coefficients.truncate(self.trimmed_len());
+    }
+
+    pub fn trimmed(mut self) -> Self {
+        self.trim();
+        self
+    }
+
+    fn trimmed_len(&self) -> usize {
+        self.coefficients
+            .iter()
+            .rposition(|coeff| !coeff.is_zero())
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Coefficient of the highest non-zero term, zero for the zero polynomial.
+    pub fn leading_coefficient(&self) -> F {
+        self.coefficients[..self.trimmed_len()]
+            .last()
+            .copied()
+            .unwrap_or(F::zero())
+    }
+
+    /// Horner's rule: one multiplication and one addition per coefficient.
     pub fn evaluate(&self, x: F) -> F {
         self.coefficients
             .iter()
-            .enumerate()
-            .map(|(i, &coef)| coef * x.pow([i as u64]))
-            .sum()
+            .rev()
+            .fold(F::zero(), |acc, &coef| acc * x + coef)
     }
+
+    /// Evaluates at every point, through a subproduct tree once there are
+    /// enough points for fast multiplication to pay off.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if points.len() < FFT_MUL_THRESHOLD {
+            return points.iter().map(|&x| self.evaluate(x)).collect();
+        }
+        SubproductTree::new(points).evaluate(self)
+    }
+
     pub fn degree(&self) -> usize {
         self.coefficients
             .iter()
@@ -44,7 +132,12 @@ impl<F: PrimeField> UnivariatePolynomial<F> {
         result
     }
 
-    fn multiply_polynomials(a: Vec<F>, b: Vec<F>) -> Vec<F> {
+    /// Schoolbook product of two coefficient vectors, of length
+    /// `a.len() + b.len() - 1`.
+    pub fn multiply_polynomials(a: &[F], b: &[F]) -> Vec<F> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
         let mut result = vec![F::zero(); a.len() + b.len() - 1];
         for i in 0..a.len() {
             for j in 0..b.len() {
@@ -54,32 +147,166 @@ impl<F: PrimeField> UnivariatePolynomial<F> {
         result
     }
 
+    /// Karatsuba product: `(a₀ + Xᵐa₁)(b₀ + Xᵐb₁)` from the three products
+    /// `a₀b₀`, `a₁b₁` and `(a₀ + a₁)(b₀ + b₁)`, recursing down to
+    /// [`KARATSUBA_MUL_THRESHOLD`]. A much longer factor is cut into pieces
+    /// the length of the shorter one first, so every split is balanced.
+    pub fn karatsuba_multiply_polynomials(a: &[F], b: &[F]) -> Vec<F> {
+        let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        if b.len() < KARATSUBA_MUL_THRESHOLD {
+            return Self::multiply_polynomials(a, b);
+        }
+
+        let mut result = vec![F::zero(); a.len() + b.len() - 1];
+        if a.len() >= 2 * b.len() {
+            for (i, chunk) in a.chunks(b.len()).enumerate() {
+                let product = Self::karatsuba_multiply_polynomials(chunk, b);
+                for (j, c) in product.into_iter().enumerate() {
+                    result[i * b.len() + j] += c;
+                }
+            }
+            return result;
+        }
+
+        let m = a.len().div_ceil(2);
+        let (a0, a1) = a.split_at(m);
+        let (b0, b1) = b.split_at(m.min(b.len()));
+        let low = Self::karatsuba_multiply_polynomials(a0, b0);
+        let high = Self::karatsuba_multiply_polynomials(a1, b1);
+        let mut middle = Self::karatsuba_multiply_polynomials(
+            &Self::add_polynomials(a0.to_vec(), a1.to_vec()),
+            &Self::add_polynomials(b0.to_vec(), b1.to_vec()),
+        );
+        for (i, c) in low.iter().enumerate() {
+            middle[i] -= c;
+            result[i] += c;
+        }
+        for (i, c) in high.iter().enumerate() {
+            middle[i] -= c;
+            result[i + 2 * m] += c;
+        }
+        for (i, c) in middle.into_iter().enumerate() {
+            if i + m < result.len() {
+                result[i + m] += c;
+            }
+        }
+        result
+    }
+
+    /// Multiplies two polynomials with schoolbook, Karatsuba or FFT products
+    /// depending on the length of the smaller factor; FFTs are only used when
+    /// the field has a 2-adic subgroup big enough for the product.
+    pub fn multiply(&self, other: &Self) -> Self {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Self::new(vec![]);
+        }
+
+        let (a, b) = (&self.coefficients, &other.coefficients);
+        let result_len = a.len() + b.len() - 1;
+        let smaller = a.len().min(b.len());
+        if smaller < KARATSUBA_MUL_THRESHOLD {
+            Self::new(Self::multiply_polynomials(a, b))
+        } else if smaller < KARATSUBA_FFT_MUL_THRESHOLD || !Self::supports_fft(result_len) {
+            Self::new(Self::karatsuba_multiply_polynomials(a, b))
+        } else {
+            Self::new(Self::fft_multiply_polynomials(a, b))
+        }
+    }
+
+    /// Whether `F` has a multiplicative subgroup of order `size.next_power_of_two()`.
+    pub fn supports_fft(size: usize) -> bool {
+        size.next_power_of_two().trailing_zeros() <= F::TWO_ADICITY
+    }
+
+    /// FFT product over the smallest power-of-two subgroup that fits it.
+    ///
+    /// Panics unless [`Self::supports_fft`] holds for the product length.
+    pub fn fft_multiply_polynomials(a: &[F], b: &[F]) -> Vec<F> {
+        fft::ntt::multiply_polynomials(a, b)
+    }
+
+    /// Evaluates the polynomial at every point of `domain`.
+    ///
+    /// Coefficients beyond the domain size are first reduced modulo the
+    /// domain's vanishing polynomial `Xⁿ - gⁿ`, which does not change the
+    /// values on the domain.
+    pub fn evaluate_over_domain(&self, domain: &EvaluationDomain<F>) -> Vec<F> {
+        let n = domain.size();
+        let mut reduced = vec![F::zero(); n];
+        let offset_pow_size = domain.offset().pow([n as u64]);
+        let mut factor = F::one();
+        for chunk in self.coefficients.chunks(n) {
+            for (i, coeff) in chunk.iter().enumerate() {
+                reduced[i] += *coeff * factor;
+            }
+            factor *= offset_pow_size;
+        }
+        domain.fft(&reduced)
+    }
+
+    /// The unique polynomial of degree below `domain.size()` taking the given
+    /// values on `domain`.
+    pub fn interpolate_over_domain(evaluations: &[F], domain: &EvaluationDomain<F>) -> Self {
+        Self::new(domain.ifft(evaluations))
+    }
+
     fn scale_polynomial(p: Vec<F>, scalar: F) -> Vec<F> {
         p.into_iter().map(|coef| coef * scalar).collect()
     }
 
-    pub fn interpolate(points: Vec<(F, F)>) -> Self {
-        let mut result = vec![F::zero(); points.len()];
-        for (i, &(x_i, y_i)) in points.iter().enumerate() {
-            let mut l_i = vec![F::one()];
-            for (j, &(x_j, _)) in points.iter().enumerate() {
-                if i != j {
-                    l_i = Self::multiply_polynomials(l_i, vec![-x_j, F::one()]);
-                    let denom = x_i - x_j;
-                    l_i = Self::scale_polynomial(l_i, denom.inverse().unwrap());
-                }
+    /// Long division: `(q, r)` with `self = q·divisor + r` and
+    /// `deg r < deg divisor`, both trimmed.
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_len = divisor.trimmed_len();
+        assert!(divisor_len > 0, "division by the zero polynomial");
+
+        let mut remainder = self.clone().trimmed();
+        if remainder.coefficients.len() < divisor_len {
+            return (Self::zero(), remainder);
+        }
+        if divisor_len >= FFT_MUL_THRESHOLD && Self::supports_fft(2 * remainder.coefficients.len())
+        {
+            // Newton iteration on the reversed divisor, with FFT products
+            let (quotient, remainder) =
+                divide_with_remainder(&remainder.coefficients, &divisor.coefficients);
+            return (Self::new(quotient), Self::new(remainder).trimmed());
+        }
+
+        let divisor = &divisor.coefficients[..divisor_len];
+        let lead_inv = divisor[divisor_len - 1].inverse().unwrap();
+        let mut quotient = vec![F::zero(); remainder.coefficients.len() - divisor_len + 1];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder.coefficients[i + divisor_len - 1] * lead_inv;
+            quotient[i] = factor;
+            for (j, d) in divisor.iter().enumerate() {
+                remainder.coefficients[i + j] -= factor * d;
             }
-            let l_i = Self::scale_polynomial(l_i, y_i);
-            result = Self::add_polynomials(result, l_i);
         }
-        Self::new(result)
+
+        remainder.coefficients.truncate(divisor_len - 1);
+        (Self::new(quotient), remainder.trimmed())
+    }
+
+    /// The polynomial of degree below `points.len()` through the given
+    /// points, in `O(n log² n)` via a subproduct tree.
+    ///
+    /// Panics if two points share an `x` coordinate.
+    pub fn interpolate(points: Vec<(F, F)>) -> Self {
+        if points.is_empty() {
+            return Self::zero();
+        }
+        let (xs, ys): (Vec<F>, Vec<F>) = points.into_iter().unzip();
+        SubproductTree::new(&xs).interpolate(&ys)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_bn254::Fq;
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::UniformRand;
 
     #[test]
     fn test_evaluate_polynomial() {
@@ -103,10 +330,93 @@ mod tests {
     fn test_multiply_polynomial() {
         let poly1 = vec![Fq::from(1), Fq::from(1)];
         let poly2 = vec![Fq::from(-1), Fq::from(1)];
-        let result = UnivariatePolynomial::multiply_polynomials(poly1, poly2);
+        let result = UnivariatePolynomial::multiply_polynomials(&poly1, &poly2);
         assert_eq!(result, vec![Fq::from(-1), Fq::from(0), Fq::from(1)]);
     }
 
+    #[test]
+    fn test_multiply_small_uses_schoolbook() {
+        let poly1 = UnivariatePolynomial::new(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
+        let poly2 = UnivariatePolynomial::new(vec![Fq::from(4), Fq::from(5)]);
+        let result = poly1.multiply(&poly2);
+        assert_eq!(
+            result.coefficients,
+            vec![Fq::from(4), Fq::from(13), Fq::from(22), Fq::from(15)]
+        );
+    }
+
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        let mut rng = ark_std::test_rng();
+        for (n, m) in [
+            (32, 32),
+            (33, 40),
+            (100, 37),
+            (257, 64),
+            (500, 31),
+            (90, 200),
+        ] {
+            let a: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+            let b: Vec<Fr> = (0..m).map(|_| Fr::rand(&mut rng)).collect();
+            assert_eq!(
+                UnivariatePolynomial::karatsuba_multiply_polynomials(&a, &b),
+                UnivariatePolynomial::multiply_polynomials(&a, &b)
+            );
+        }
+    }
+
+    #[test]
+    fn test_multiply_large_uses_fft() {
+        let mut rng = ark_std::test_rng();
+        let poly1 = UnivariatePolynomial::new((0..100).map(|_| Fr::rand(&mut rng)).collect());
+        let poly2 = UnivariatePolynomial::new((0..70).map(|_| Fr::rand(&mut rng)).collect());
+        assert!(UnivariatePolynomial::<Fr>::supports_fft(169));
+
+        let expected =
+            UnivariatePolynomial::multiply_polynomials(&poly1.coefficients, &poly2.coefficients);
+        assert_eq!(poly1.multiply(&poly2).coefficients, expected);
+    }
+
+    #[test]
+    fn test_multiply_falls_back_without_two_adic_subgroup() {
+        // the BN254 base field only has a subgroup of order 2
+        assert!(!UnivariatePolynomial::<Fq>::supports_fft(128));
+
+        let poly1 = UnivariatePolynomial::new((1..=80).map(Fq::from).collect());
+        let poly2 = UnivariatePolynomial::new((1..=80).map(Fq::from).collect());
+        let expected =
+            UnivariatePolynomial::multiply_polynomials(&poly1.coefficients, &poly2.coefficients);
+        assert_eq!(poly1.multiply(&poly2).coefficients, expected);
+    }
+
+    #[test]
+    fn test_evaluate_over_domain() {
+        let polynomial = UnivariatePolynomial::new((1..=12).map(Fr::from).collect());
+        let domain = EvaluationDomain::<Fr>::new(8);
+
+        // more coefficients than points: the high part wraps around
+        let evaluations = polynomial.evaluate_over_domain(&domain);
+        for (i, x) in domain.elements().into_iter().enumerate() {
+            assert_eq!(evaluations[i], polynomial.evaluate(x));
+        }
+
+        let coset = domain.get_coset(Fr::from(7));
+        let evaluations = polynomial.evaluate_over_domain(&coset);
+        for (i, x) in coset.elements().into_iter().enumerate() {
+            assert_eq!(evaluations[i], polynomial.evaluate(x));
+        }
+    }
+
+    #[test]
+    fn test_interpolate_over_domain() {
+        let polynomial = UnivariatePolynomial::new((1..=8).map(Fr::from).collect());
+        let domain = EvaluationDomain::<Fr>::new(8);
+        let evaluations = polynomial.evaluate_over_domain(&domain);
+
+        let interpolated = UnivariatePolynomial::interpolate_over_domain(&evaluations, &domain);
+        assert_eq!(interpolated.coefficients, polynomial.coefficients);
+    }
+
     #[test]
     fn test_interpolate_known_points() {
         let points = vec![
@@ -125,4 +435,101 @@ mod tests {
         assert_eq!(polynomial.evaluate(Fq::from(2)), Fq::from(4));
         assert_eq!(polynomial.evaluate(Fq::from(3)), Fq::from(6));
     }
+
+    #[test]
+    fn test_evaluate_many() {
+        let mut rng = ark_std::test_rng();
+        let polynomial = UnivariatePolynomial::new((0..90).map(|_| Fr::rand(&mut rng)).collect());
+        for n in [3, 200] {
+            let points: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+            let expected: Vec<Fr> = points.iter().map(|&x| polynomial.evaluate(x)).collect();
+            assert_eq!(polynomial.evaluate_many(&points), expected);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_keeps_one_coefficient_per_point() {
+        // three points on a line still give three coefficients
+        let points = vec![
+            (Fr::from(0), Fr::from(1)),
+            (Fr::from(1), Fr::from(3)),
+            (Fr::from(2), Fr::from(5)),
+        ];
+        let polynomial = UnivariatePolynomial::interpolate(points);
+        assert_eq!(
+            polynomial.coefficients,
+            vec![Fr::from(1), Fr::from(2), Fr::from(0)]
+        );
+        assert!(UnivariatePolynomial::<Fr>::interpolate(vec![]).is_zero());
+    }
+
+    #[test]
+    fn test_trim_and_equality() {
+        let mut polynomial = UnivariatePolynomial::new(vec![Fq::from(1), Fq::from(2), Fq::from(0)]);
+        assert_eq!(
+            polynomial,
+            UnivariatePolynomial::new(vec![Fq::from(1), Fq::from(2)])
+        );
+        assert_ne!(polynomial, UnivariatePolynomial::new(vec![Fq::from(1)]));
+
+        polynomial.trim();
+        assert_eq!(polynomial.coefficients, vec![Fq::from(1), Fq::from(2)]);
+        assert_eq!(polynomial.leading_coefficient(), Fq::from(2));
+
+        let zero = UnivariatePolynomial::new(vec![Fq::from(0); 3]);
+        assert!(zero.is_zero());
+        assert_eq!(zero, UnivariatePolynomial::zero());
+        assert!(zero.trimmed().coefficients.is_empty());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // x³ - 2x + 5 = (x² + x - 1)(x - 1) + 4
+        let dividend =
+            UnivariatePolynomial::new(vec![Fq::from(5), Fq::from(-2), Fq::from(0), Fq::from(1)]);
+        let divisor = UnivariatePolynomial::new(vec![Fq::from(-1), Fq::from(1)]);
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert_eq!(
+            quotient.coefficients,
+            vec![Fq::from(-1), Fq::from(1), Fq::from(1)]
+        );
+        assert_eq!(remainder.coefficients, vec![Fq::from(4)]);
+    }
+
+    #[test]
+    fn test_div_rem_reconstructs_dividend() {
+        let mut rng = ark_std::test_rng();
+        let dividend = UnivariatePolynomial::new((0..20).map(|_| Fr::rand(&mut rng)).collect());
+        let mut divisor: Vec<Fr> = (0..7).map(|_| Fr::rand(&mut rng)).collect();
+        divisor.extend([Fr::from(0), Fr::from(0)]);
+        let divisor = UnivariatePolynomial::new(divisor);
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert!(remainder.coefficients.len() < 7);
+        assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+
+        // a lower-degree dividend is its own remainder
+        let (quotient, remainder) = divisor.div_rem(&dividend);
+        assert!(quotient.is_zero());
+        assert_eq!(remainder, divisor);
+    }
+
+    #[test]
+    fn test_div_rem_large_divisor_uses_newton() {
+        let mut rng = ark_std::test_rng();
+        let dividend = UnivariatePolynomial::new((0..300).map(|_| Fr::rand(&mut rng)).collect());
+        let divisor = UnivariatePolynomial::new((0..100).map(|_| Fr::rand(&mut rng)).collect());
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert_eq!(quotient.coefficients.len(), 201);
+        assert!(remainder.coefficients.len() < 100);
+        assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn test_div_rem_by_zero() {
+        let polynomial = UnivariatePolynomial::new(vec![Fq::from(1)]);
+        polynomial.div_rem(&UnivariatePolynomial::new(vec![Fq::from(0)]));
+    }
 }
@@ -0,0 +1,214 @@
+//! Textual notation for polynomials, like `3x^2 - x + 7`.
+//!
+//! Terms are separated by `+` or `-`, whitespace is ignored, and a
+//! coefficient is a decimal integer that may be followed by `*`. Repeated
+//! monomials are added up. Coefficients print as the smaller of `c` and
+//! `p - c`, so `-1` round-trips instead of showing as `p - 1`.
+//!
+//! Parsing rejects exponents above [`MAX_PARSED_DEGREE`], so a short string
+//! cannot ask for a huge coefficient vector.
+//!
+//! The sign handling is shared with `multivariate_poly`, whose multilinear
+//! polynomials use the same notation over named variables.
+
+use crate::UnivariatePolynomial;
+use ark_ff::PrimeField;
+use std::fmt;
+use std::str::FromStr;
+
+/// Highest exponent [`FromStr`] accepts.
+pub const MAX_PARSED_DEGREE: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePolynomialError(pub String);
+
+impl fmt::Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid polynomial: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePolynomialError {}
+
+/// Splits `text` into terms, each as its coefficient and the remaining
+/// monomial text, e.g. `"3x^2 - x"` into `[(3, "x^2"), (-1, "x")]`.
+pub fn parse_terms<F: PrimeField>(text: &str) -> Result<Vec<(F, String)>, ParsePolynomialError> {
+    let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if text.is_empty() {
+        return Err(ParsePolynomialError("empty input".into()));
+    }
+
+    let mut terms = vec![];
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        let negative = rest.starts_with('-');
+        rest = rest.strip_prefix(['+', '-']).unwrap_or(rest);
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let (term, tail) = rest.split_at(end);
+        if term.is_empty() {
+            return Err(ParsePolynomialError(format!("missing term in {text:?}")));
+        }
+
+        let digits = term
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(term.len());
+        let (coefficient, monomial) = term.split_at(digits);
+        let coefficient = if coefficient.is_empty() {
+            F::one()
+        } else {
+            F::from_str(coefficient)
+                .map_err(|_| ParsePolynomialError(format!("bad coefficient {coefficient:?}")))?
+        };
+        let monomial = match monomial.strip_prefix('*') {
+            Some(monomial) if digits > 0 && !monomial.is_empty() => monomial,
+            Some(_) => return Err(ParsePolynomialError(format!("misplaced '*' in {term:?}"))),
+            None => monomial,
+        };
+
+        terms.push((
+            if negative { -coefficient } else { coefficient },
+            monomial.to_string(),
+        ));
+        rest = tail;
+    }
+    Ok(terms)
+}
+
+/// Joins `(coefficient, monomial)` terms with `+` and `-`, leaving out
+/// unit coefficients in front of a monomial and writing `0` for no terms.
+pub fn format_terms<F: PrimeField>(terms: &[(F, String)]) -> String {
+    let mut out = String::new();
+    for (i, (coefficient, monomial)) in terms.iter().enumerate() {
+        let negative = coefficient.into_bigint() > F::MODULUS_MINUS_ONE_DIV_TWO;
+        let magnitude = if negative {
+            -*coefficient
+        } else {
+            *coefficient
+        };
+        out += match (i, negative) {
+            (0, false) => "",
+            (0, true) => "-",
+            (_, false) => " + ",
+            (_, true) => " - ",
+        };
+        if !magnitude.is_one() || monomial.is_empty() {
+            out += &magnitude.to_string();
+        }
+        out += monomial;
+    }
+    if out.is_empty() {
+        out += "0";
+    }
+    out
+}
+
+/// Descending powers of `x`, e.g. `3x^2 + 2x + 1`.
+impl<F: PrimeField> fmt::Display for UnivariatePolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<(F, String)> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(degree, &coefficient)| {
+                let monomial = match degree {
+                    0 => String::new(),
+                    1 => "x".into(),
+                    _ => format!("x^{degree}"),
+                };
+                (coefficient, monomial)
+            })
+            .collect();
+        f.write_str(&format_terms(&terms))
+    }
+}
+
+impl<F: PrimeField> FromStr for UnivariatePolynomial<F> {
+    type Err = ParsePolynomialError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut coefficients = vec![];
+        for (coefficient, monomial) in parse_terms::<F>(text)? {
+            let degree: usize = match monomial.as_str() {
+                "" => 0,
+                "x" => 1,
+                _ => monomial
+                    .strip_prefix("x^")
+                    .and_then(|exponent| exponent.parse().ok())
+                    .ok_or_else(|| ParsePolynomialError(format!("bad monomial {monomial:?}")))?,
+            };
+            let len = degree
+                .checked_add(1)
+                .filter(|&len| len <= MAX_PARSED_DEGREE + 1)
+                .ok_or_else(|| {
+                    ParsePolynomialError(format!(
+                        "degree {degree} exceeds the limit of {MAX_PARSED_DEGREE}"
+                    ))
+                })?;
+            if coefficients.len() < len {
+                coefficients.resize(len, F::zero());
+            }
+            coefficients[degree] += coefficient;
+        }
+        Ok(Self::new(coefficients).trimmed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    fn poly(coefficients: &[i64]) -> UnivariatePolynomial<Fr> {
+        UnivariatePolynomial::new(coefficients.iter().map(|&c| Fr::from(c)).collect())
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(poly(&[1, 2, 3]).to_string(), "3x^2 + 2x + 1");
+        assert_eq!(poly(&[-7, 0, 0, -1]).to_string(), "-x^3 - 7");
+        assert_eq!(poly(&[0, 1]).to_string(), "x");
+        assert_eq!(poly(&[5, 0, 0]).to_string(), "5");
+        assert_eq!(UnivariatePolynomial::<Fr>::zero().to_string(), "0");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3x^2 + 2x + 1".parse(), Ok(poly(&[1, 2, 3])));
+        assert_eq!("-x^3-7".parse(), Ok(poly(&[-7, 0, 0, -1])));
+        assert_eq!(" 2*x^2 + x^2 - 4 * x ".parse(), Ok(poly(&[0, -4, 3])));
+        assert_eq!("x - x".parse(), Ok(UnivariatePolynomial::<Fr>::zero()));
+        assert_eq!("0".parse(), Ok(UnivariatePolynomial::<Fr>::zero()));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for text in ["12x^40 - 3x^7 + x + 9", "-x", "x^2 - 1"] {
+            let polynomial: UnivariatePolynomial<Fr> = text.parse().unwrap();
+            assert_eq!(polynomial.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let too_high = format!("x^{}", MAX_PARSED_DEGREE + 1);
+        for text in [
+            "",
+            "3x^",
+            "2y",
+            "x +",
+            "3 + + x",
+            "*x",
+            "x^2^3",
+            "x^18446744073709551615",
+            "x^18446744073709551616",
+            too_high.as_str(),
+        ] {
+            assert!(
+                text.parse::<UnivariatePolynomial<Fr>>().is_err(),
+                "{text:?} should not parse"
+            );
+        }
+    }
+}
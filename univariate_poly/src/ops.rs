@@ -0,0 +1,155 @@
+use crate::UnivariatePolynomial;
+use ark_ff::PrimeField;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Implements a binary operator for every mix of owned and borrowed
+/// operands in terms of the borrowed-borrowed version.
+macro_rules! impl_binary_op {
+    ($op:ident, $method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<F: PrimeField> $op<&UnivariatePolynomial<F>> for &UnivariatePolynomial<F> {
+            type Output = UnivariatePolynomial<F>;
+
+            fn $method(self, other: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+                let ($a, $b) = (self, other);
+                $body
+            }
+        }
+
+        impl<F: PrimeField> $op<UnivariatePolynomial<F>> for UnivariatePolynomial<F> {
+            type Output = UnivariatePolynomial<F>;
+
+            fn $method(self, other: UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<F: PrimeField> $op<&UnivariatePolynomial<F>> for UnivariatePolynomial<F> {
+            type Output = UnivariatePolynomial<F>;
+
+            fn $method(self, other: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+                (&self).$method(other)
+            }
+        }
+
+        impl<F: PrimeField> $op<UnivariatePolynomial<F>> for &UnivariatePolynomial<F> {
+            type Output = UnivariatePolynomial<F>;
+
+            fn $method(self, other: UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+                self.$method(&other)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, |a, b| {
+    UnivariatePolynomial::new(UnivariatePolynomial::add_polynomials(
+        a.coefficients.clone(),
+        b.coefficients.clone(),
+    ))
+    .trimmed()
+});
+
+impl_binary_op!(Sub, sub, |a, b| a + &(-b));
+
+impl_binary_op!(Mul, mul, |a, b| a.multiply(b).trimmed());
+
+impl<F: PrimeField> Neg for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn neg(self) -> UnivariatePolynomial<F> {
+        UnivariatePolynomial::new(self.coefficients.iter().map(|c| -*c).collect())
+    }
+}
+
+impl<F: PrimeField> Neg for UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn neg(self) -> UnivariatePolynomial<F> {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn mul(self, scalar: F) -> UnivariatePolynomial<F> {
+        UnivariatePolynomial::new(UnivariatePolynomial::scale_polynomial(
+            self.coefficients.clone(),
+            scalar,
+        ))
+        .trimmed()
+    }
+}
+
+impl<F: PrimeField> Mul<F> for UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn mul(self, scalar: F) -> UnivariatePolynomial<F> {
+        &self * scalar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    fn poly(coefficients: &[i64]) -> UnivariatePolynomial<Fr> {
+        UnivariatePolynomial::new(coefficients.iter().map(|&c| Fr::from(c)).collect())
+    }
+
+    #[test]
+    fn test_add_and_sub() {
+        let a = poly(&[1, 2, 3]);
+        let b = poly(&[4, 5]);
+        assert_eq!(&a + &b, poly(&[5, 7, 3]));
+        assert_eq!(a.clone() + b.clone(), poly(&[5, 7, 3]));
+        assert_eq!(&a - &b, poly(&[-3, -3, 3]));
+        assert_eq!(b.clone() - a.clone(), poly(&[3, 3, -3]));
+        assert_eq!(a.clone() - &a, UnivariatePolynomial::zero());
+    }
+
+    #[test]
+    fn test_cancellation_is_trimmed() {
+        let a = poly(&[1, 2, 3]);
+        let b = poly(&[0, 0, -3]);
+        let sum = &a + &b;
+        assert_eq!(sum.coefficients, poly(&[1, 2]).coefficients);
+        assert!((&a - &a).coefficients.is_empty());
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(-poly(&[1, -2, 3]), poly(&[-1, 2, -3]));
+        assert_eq!(
+            -&UnivariatePolynomial::<Fr>::zero(),
+            UnivariatePolynomial::zero()
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        // (x + 1)(x - 1) = x² - 1
+        let product = poly(&[1, 1]) * poly(&[-1, 1]);
+        assert_eq!(product, poly(&[-1, 0, 1]));
+        assert_eq!(
+            &poly(&[1, 2]) * &UnivariatePolynomial::zero(),
+            UnivariatePolynomial::zero()
+        );
+    }
+
+    #[test]
+    fn test_mul_by_scalar() {
+        assert_eq!(&poly(&[1, 2, 3]) * Fr::from(2), poly(&[2, 4, 6]));
+        assert!((poly(&[1, 2, 3]) * Fr::from(0)).is_zero());
+    }
+
+    #[test]
+    fn test_algebraic_identities() {
+        let a = poly(&[3, 0, 1, 4]);
+        let b = poly(&[2, 7]);
+        let c = poly(&[-1, 5, 9]);
+        assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+        assert_eq!(&(&a - &b) + &b, a);
+    }
+}
@@ -0,0 +1,144 @@
+//! `CanonicalSerialize`/`CanonicalDeserialize` for [`UnivariatePolynomial`]:
+//! the coefficient count as a little-endian `u64`, then each coefficient in
+//! arkworks' field encoding. Trailing zeros are dropped, so equal
+//! polynomials always serialize to the same bytes, and the length prefix
+//! keeps consecutive polynomials in a transcript apart.
+//!
+//! With the `serde` feature the same bytes back `Serialize`/`Deserialize`.
+
+use crate::UnivariatePolynomial;
+use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+
+impl<F: PrimeField> CanonicalSerialize for UnivariatePolynomial<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.coefficients[..self.trimmed_len()].serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coefficients[..self.trimmed_len()].serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for UnivariatePolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.coefficients.last().is_some_and(|c| c.is_zero()) {
+            return Err(SerializationError::InvalidData);
+        }
+        self.coefficients.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for UnivariatePolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let polynomial = Self::new(Vec::deserialize_with_mode(reader, compress, Validate::No)?);
+        if validate == Validate::Yes {
+            polynomial.check()?;
+        }
+        Ok(polynomial)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<F: PrimeField> serde::Serialize for UnivariatePolynomial<F> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = vec![];
+        self.serialize_compressed(&mut bytes)
+            .map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: PrimeField> serde::Deserialize<'de> for UnivariatePolynomial<F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: Vec<u8> = serde::Deserialize::deserialize(deserializer)?;
+        Self::deserialize_compressed(bytes.as_slice()).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    fn poly(coefficients: &[i64]) -> UnivariatePolynomial<Fr> {
+        UnivariatePolynomial::new(coefficients.iter().map(|&c| Fr::from(c)).collect())
+    }
+
+    fn to_bytes(polynomial: &UnivariatePolynomial<Fr>) -> Vec<u8> {
+        let mut bytes = vec![];
+        polynomial.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for polynomial in [poly(&[3, -1, 4]), poly(&[7]), UnivariatePolynomial::zero()] {
+            let bytes = to_bytes(&polynomial);
+            assert_eq!(bytes.len(), polynomial.serialized_size(Compress::Yes));
+            let decoded =
+                UnivariatePolynomial::<Fr>::deserialize_compressed(bytes.as_slice()).unwrap();
+            assert_eq!(decoded.coefficients, polynomial.coefficients);
+        }
+    }
+
+    #[test]
+    fn test_length_prefix() {
+        let bytes = to_bytes(&poly(&[1, 2]));
+        assert_eq!(bytes[..8], 2u64.to_le_bytes());
+        assert_eq!(bytes.len(), 8 + 2 * 32);
+
+        // [1, 2] then [3] differs from [1] then [2, 3] once lengths are included
+        let mut first = to_bytes(&poly(&[1, 2]));
+        first.extend(to_bytes(&poly(&[3])));
+        let mut second = to_bytes(&poly(&[1]));
+        second.extend(to_bytes(&poly(&[2, 3])));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_equal_polynomials_encode_equally() {
+        assert_eq!(to_bytes(&poly(&[5, 6, 0, 0])), to_bytes(&poly(&[5, 6])));
+        assert_eq!(
+            to_bytes(&poly(&[0, 0])),
+            to_bytes(&UnivariatePolynomial::zero())
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_canonical_input() {
+        let mut bytes = vec![];
+        vec![Fr::from(1), Fr::from(0)]
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert!(UnivariatePolynomial::<Fr>::deserialize_compressed(bytes.as_slice()).is_err());
+        assert!(
+            UnivariatePolynomial::<Fr>::deserialize_compressed_unchecked(bytes.as_slice()).is_ok()
+        );
+
+        // truncated input
+        let bytes = to_bytes(&poly(&[1, 2]));
+        assert!(UnivariatePolynomial::<Fr>::deserialize_compressed(&bytes[..40]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let polynomial = poly(&[9, 0, -2]);
+        let json = serde_json::to_string(&polynomial).unwrap();
+        let decoded: UnivariatePolynomial<Fr> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, polynomial);
+    }
+}
@@ -0,0 +1,421 @@
+use crate::UnivariatePolynomial;
+use ark_ff::PrimeField;
+use fft::domain::EvaluationDomain;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A polynomial stored as `(exponent, coefficient)` terms, sorted by
+/// exponent with no zero coefficients, for polynomials like `Xⁿ - 1` that
+/// have a high degree but only a few terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsePolynomial<F: PrimeField> {
+    terms: Vec<(usize, F)>,
+}
+
+impl<F: PrimeField> SparsePolynomial<F> {
+    /// Sorts the terms, adding up repeated exponents and dropping zeros.
+    pub fn new(mut terms: Vec<(usize, F)>) -> Self {
+        terms.sort_by_key(|&(exponent, _)| exponent);
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(terms.len());
+        for (exponent, coeff) in terms {
+            match merged.last_mut() {
+                Some((last, sum)) if *last == exponent => *sum += coeff,
+                _ => merged.push((exponent, coeff)),
+            }
+        }
+        merged.retain(|(_, coeff)| !coeff.is_zero());
+        Self { terms: merged }
+    }
+
+    pub fn zero() -> Self {
+        Self { terms: vec![] }
+    }
+
+    /// `coeff·X^exponent`.
+    pub fn monomial(exponent: usize, coeff: F) -> Self {
+        Self::new(vec![(exponent, coeff)])
+    }
+
+    /// `Xⁿ - offsetⁿ`, which vanishes exactly on `domain`.
+    pub fn vanishing(domain: &EvaluationDomain<F>) -> Self {
+        let n = domain.size();
+        Self::new(vec![(0, -domain.offset().pow([n as u64])), (n, F::one())])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn terms(&self) -> &[(usize, F)] {
+        &self.terms
+    }
+
+    pub fn degree(&self) -> usize {
+        self.terms.last().map_or(0, |&(exponent, _)| exponent)
+    }
+
+    /// Raises `x` only by the gaps between consecutive exponents, so the cost
+    /// is `O(t·log(degree))` for `t` terms.
+    pub fn evaluate(&self, x: F) -> F {
+        let mut power = F::one();
+        let mut previous = 0;
+        let mut result = F::zero();
+        for &(exponent, coeff) in &self.terms {
+            power *= x.pow([(exponent - previous) as u64]);
+            previous = exponent;
+            result += coeff * power;
+        }
+        result
+    }
+
+    pub fn to_dense(&self) -> UnivariatePolynomial<F> {
+        let mut coefficients = vec![F::zero(); self.terms.last().map_or(0, |&(e, _)| e + 1)];
+        for &(exponent, coeff) in &self.terms {
+            coefficients[exponent] = coeff;
+        }
+        UnivariatePolynomial::new(coefficients)
+    }
+
+    pub fn from_dense(polynomial: &UnivariatePolynomial<F>) -> Self {
+        Self {
+            terms: polynomial
+                .coefficients
+                .iter()
+                .enumerate()
+                .filter(|(_, coeff)| !coeff.is_zero())
+                .map(|(exponent, &coeff)| (exponent, coeff))
+                .collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> From<SparsePolynomial<F>> for UnivariatePolynomial<F> {
+    fn from(polynomial: SparsePolynomial<F>) -> Self {
+        polynomial.to_dense()
+    }
+}
+
+impl<F: PrimeField> From<UnivariatePolynomial<F>> for SparsePolynomial<F> {
+    fn from(polynomial: UnivariatePolynomial<F>) -> Self {
+        Self::from_dense(&polynomial)
+    }
+}
+
+impl<F: PrimeField> UnivariatePolynomial<F> {
+    /// Long division by a sparse divisor in `O(n·t)` for `t` divisor terms,
+    /// e.g. by a vanishing polynomial `Xⁿ - c` in linear time.
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem_sparse(&self, divisor: &SparsePolynomial<F>) -> (Self, Self) {
+        let &(divisor_degree, lead) = divisor
+            .terms
+            .last()
+            .expect("division by the zero polynomial");
+        let mut remainder = self.clone().trimmed();
+        if remainder.coefficients.len() <= divisor_degree {
+            return (Self::zero(), remainder);
+        }
+
+        let lead_inv = lead.inverse().unwrap();
+        let lower_terms = &divisor.terms[..divisor.terms.len() - 1];
+        let mut quotient = vec![F::zero(); remainder.coefficients.len() - divisor_degree];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder.coefficients[i + divisor_degree] * lead_inv;
+            quotient[i] = factor;
+            remainder.coefficients[i + divisor_degree] = F::zero();
+            for &(exponent, coeff) in lower_terms {
+                remainder.coefficients[i + exponent] -= factor * coeff;
+            }
+        }
+
+        remainder.coefficients.truncate(divisor_degree);
+        (Self::new(quotient), remainder.trimmed())
+    }
+}
+
+/// Implements a binary operator for owned operands in terms of the
+/// borrowed-borrowed version.
+macro_rules! forward_owned {
+    ($op:ident, $method:ident, $lhs:ty, $rhs:ty, $output:ty) => {
+        impl<F: PrimeField> $op<$rhs> for $lhs {
+            type Output = $output;
+
+            fn $method(self, other: $rhs) -> $output {
+                (&self).$method(&other)
+            }
+        }
+    };
+}
+
+impl<F: PrimeField> Add<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn add(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        SparsePolynomial::new(self.terms.iter().chain(&other.terms).copied().collect())
+    }
+}
+
+impl<F: PrimeField> Sub<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn sub(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        self + &(-other)
+    }
+}
+
+impl<F: PrimeField> Mul<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        SparsePolynomial::new(
+            self.terms
+                .iter()
+                .flat_map(|&(e, a)| other.terms.iter().map(move |&(f, b)| (e + f, a * b)))
+                .collect(),
+        )
+    }
+}
+
+impl<F: PrimeField> Neg for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn neg(self) -> SparsePolynomial<F> {
+        SparsePolynomial {
+            terms: self.terms.iter().map(|&(e, c)| (e, -c)).collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> Neg for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn neg(self) -> SparsePolynomial<F> {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, scalar: F) -> SparsePolynomial<F> {
+        SparsePolynomial::new(self.terms.iter().map(|&(e, c)| (e, c * scalar)).collect())
+    }
+}
+
+impl<F: PrimeField> Add<&SparsePolynomial<F>> for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn add(self, other: &SparsePolynomial<F>) -> UnivariatePolynomial<F> {
+        let mut sum = self.clone();
+        if sum.coefficients.len() <= other.degree() && !other.is_zero() {
+            sum.coefficients.resize(other.degree() + 1, F::zero());
+        }
+        for &(exponent, coeff) in &other.terms {
+            sum.coefficients[exponent] += coeff;
+        }
+        sum.trimmed()
+    }
+}
+
+impl<F: PrimeField> Add<&UnivariatePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn add(self, other: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        other + self
+    }
+}
+
+impl<F: PrimeField> Sub<&SparsePolynomial<F>> for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn sub(self, other: &SparsePolynomial<F>) -> UnivariatePolynomial<F> {
+        self + &(-other)
+    }
+}
+
+impl<F: PrimeField> Sub<&UnivariatePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn sub(self, other: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        &(-other) + self
+    }
+}
+
+/// Shifted copies of the dense factor, one per sparse term: `O(n·t)`.
+impl<F: PrimeField> Mul<&SparsePolynomial<F>> for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn mul(self, other: &SparsePolynomial<F>) -> UnivariatePolynomial<F> {
+        if self.is_zero() || other.is_zero() {
+            return UnivariatePolynomial::zero();
+        }
+        let mut product = vec![F::zero(); self.coefficients.len() + other.degree()];
+        for &(exponent, coeff) in &other.terms {
+            for (i, c) in self.coefficients.iter().enumerate() {
+                product[i + exponent] += coeff * c;
+            }
+        }
+        UnivariatePolynomial::new(product).trimmed()
+    }
+}
+
+impl<F: PrimeField> Mul<&UnivariatePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn mul(self, other: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        other * self
+    }
+}
+
+forward_owned!(
+    Add,
+    add,
+    SparsePolynomial<F>,
+    SparsePolynomial<F>,
+    SparsePolynomial<F>
+);
+forward_owned!(
+    Sub,
+    sub,
+    SparsePolynomial<F>,
+    SparsePolynomial<F>,
+    SparsePolynomial<F>
+);
+forward_owned!(
+    Mul,
+    mul,
+    SparsePolynomial<F>,
+    SparsePolynomial<F>,
+    SparsePolynomial<F>
+);
+forward_owned!(
+    Add,
+    add,
+    UnivariatePolynomial<F>,
+    SparsePolynomial<F>,
+    UnivariatePolynomial<F>
+);
+forward_owned!(
+    Add,
+    add,
+    SparsePolynomial<F>,
+    UnivariatePolynomial<F>,
+    UnivariatePolynomial<F>
+);
+forward_owned!(
+    Sub,
+    sub,
+    UnivariatePolynomial<F>,
+    SparsePolynomial<F>,
+    UnivariatePolynomial<F>
+);
+forward_owned!(
+    Sub,
+    sub,
+    SparsePolynomial<F>,
+    UnivariatePolynomial<F>,
+    UnivariatePolynomial<F>
+);
+forward_owned!(
+    Mul,
+    mul,
+    UnivariatePolynomial<F>,
+    SparsePolynomial<F>,
+    UnivariatePolynomial<F>
+);
+forward_owned!(
+    Mul,
+    mul,
+    SparsePolynomial<F>,
+    UnivariatePolynomial<F>,
+    UnivariatePolynomial<F>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::{FftField, Field, UniformRand, Zero};
+
+    fn sparse(terms: &[(usize, i64)]) -> SparsePolynomial<Fr> {
+        SparsePolynomial::new(terms.iter().map(|&(e, c)| (e, Fr::from(c))).collect())
+    }
+
+    fn dense(coefficients: &[i64]) -> UnivariatePolynomial<Fr> {
+        UnivariatePolynomial::new(coefficients.iter().map(|&c| Fr::from(c)).collect())
+    }
+
+    #[test]
+    fn test_new_normalises_terms() {
+        let polynomial = sparse(&[(5, 1), (0, 2), (5, 3), (2, 4), (2, -4)]);
+        assert_eq!(polynomial.terms(), &[(0, Fr::from(2)), (5, Fr::from(4))]);
+        assert_eq!(polynomial.degree(), 5);
+        assert!(sparse(&[(3, 1), (3, -1)]).is_zero());
+    }
+
+    #[test]
+    fn test_evaluate_high_degree() {
+        // X^(2^20) - 1 vanishes on the 2^20-th roots of unity
+        let polynomial = sparse(&[(1 << 20, 1), (0, -1)]);
+        let root = Fr::get_root_of_unity(1 << 20).unwrap();
+        assert!(polynomial.evaluate(root).is_zero());
+        assert!(polynomial.evaluate(root * root).is_zero());
+        assert_eq!(polynomial.evaluate(Fr::from(1)), Fr::from(0));
+        assert_eq!(
+            polynomial.evaluate(Fr::from(2)),
+            Fr::from(2).pow([1 << 20]) - Fr::from(1)
+        );
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let polynomial = sparse(&[(0, 3), (4, -1), (7, 2)]);
+        let as_dense = polynomial.to_dense();
+        assert_eq!(as_dense, dense(&[3, 0, 0, 0, -1, 0, 0, 2]));
+        assert_eq!(SparsePolynomial::from(as_dense), polynomial);
+        assert!(SparsePolynomial::<Fr>::zero().to_dense().is_zero());
+
+        let x = Fr::from(9);
+        assert_eq!(polynomial.evaluate(x), polynomial.to_dense().evaluate(x));
+    }
+
+    #[test]
+    fn test_arithmetic_matches_dense() {
+        let a = sparse(&[(0, 1), (3, 2), (10, -5)]);
+        let b = sparse(&[(3, -2), (4, 7)]);
+        let c = dense(&[4, -1, 0, 6, 2]);
+
+        assert_eq!((&a + &b).to_dense(), &a.to_dense() + &b.to_dense());
+        assert_eq!((&a - &b).to_dense(), &a.to_dense() - &b.to_dense());
+        assert_eq!((&a * &b).to_dense(), &a.to_dense() * &b.to_dense());
+        assert_eq!((&a * Fr::from(3)).to_dense(), &a.to_dense() * Fr::from(3));
+        assert!((&a - &a).is_zero());
+
+        assert_eq!(&c + &a, &c + &a.to_dense());
+        assert_eq!(&a + &c, &c + &a.to_dense());
+        assert_eq!(&c - &a, &c - &a.to_dense());
+        assert_eq!(&a - &c, &a.to_dense() - &c);
+        assert_eq!(&c * &a, &c * &a.to_dense());
+        assert_eq!(a.clone() * c.clone(), &c * &a.to_dense());
+    }
+
+    #[test]
+    fn test_div_rem_by_vanishing_polynomial() {
+        let mut rng = ark_std::test_rng();
+        let domain = EvaluationDomain::<Fr>::new(8).get_coset(Fr::from(5));
+        let vanishing = SparsePolynomial::vanishing(&domain);
+        assert!(domain
+            .elements()
+            .iter()
+            .all(|&x| vanishing.evaluate(x).is_zero()));
+
+        let quotient = UnivariatePolynomial::new((0..20).map(|_| Fr::rand(&mut rng)).collect());
+        let remainder = UnivariatePolynomial::new((0..5).map(|_| Fr::rand(&mut rng)).collect());
+        let dividend = &(&quotient * &vanishing) + &remainder;
+
+        assert_eq!(dividend.div_rem_sparse(&vanishing), (quotient, remainder));
+        assert_eq!(
+            dividend.div_rem_sparse(&vanishing),
+            dividend.div_rem(&vanishing.to_dense())
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use crate::UnivariatePolynomial;
+use ark_ff::{batch_inversion, PrimeField};
+
+/// Products of `X - xᵢ` over ever larger groups of points, for evaluating
+/// at or interpolating through many arbitrary points in `O(n log² n)`.
+///
+/// Layer 0 holds the linear factors; each node above is the product of two
+/// neighbours, and a node without a partner moves up unchanged. The root is
+/// the vanishing polynomial of all the points.
+#[derive(Debug, Clone)]
+pub struct SubproductTree<F: PrimeField> {
+    points: Vec<F>,
+    layers: Vec<Vec<UnivariatePolynomial<F>>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    pub fn new(points: &[F]) -> Self {
+        assert!(!points.is_empty(), "need at least one point");
+
+        let leaves = points
+            .iter()
+            .map(|&x| UnivariatePolynomial::new(vec![-x, F::one()]))
+            .collect();
+        let mut layers: Vec<Vec<UnivariatePolynomial<F>>> = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self {
+            points: points.to_vec(),
+            layers,
+        }
+    }
+
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// `Π (X - xᵢ)` over all the points.
+    pub fn vanishing_polynomial(&self) -> &UnivariatePolynomial<F> {
+        &self.layers.last().unwrap()[0]
+    }
+
+    /// Evaluates `polynomial` at every point by reducing it modulo each node
+    /// on the way down; the remainder at leaf `i` is `f(xᵢ)`.
+    pub fn evaluate(&self, polynomial: &UnivariatePolynomial<F>) -> Vec<F> {
+        let mut remainders = vec![polynomial.div_rem(self.vanishing_polynomial()).1];
+        for layer in self.layers.iter().rev().skip(1) {
+            remainders = layer
+                .iter()
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].div_rem(node).1)
+                .collect();
+        }
+        remainders
+            .iter()
+            .map(|remainder| remainder.coefficients.first().copied().unwrap_or(F::zero()))
+            .collect()
+    }
+
+    /// The polynomial of degree below `n` taking `values[i]` at point `i`.
+    ///
+    /// With `M` the vanishing polynomial, the Lagrange weights are
+    /// `1/M'(xᵢ)`, and the weighted values are combined up the tree as
+    /// `f = f_left·M_right + f_right·M_left`.
+    pub fn interpolate(&self, values: &[F]) -> UnivariatePolynomial<F> {
+        assert_eq!(
+            values.len(),
+            self.points.len(),
+            "expected one value per point"
+        );
+
+        let mut weights = self.evaluate(&self.vanishing_polynomial().derivative());
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "interpolation points must be distinct"
+        );
+        batch_inversion(&mut weights);
+
+        let mut nodes: Vec<UnivariatePolynomial<F>> = values
+            .iter()
+            .zip(&weights)
+            .map(|(&y, &w)| UnivariatePolynomial::new(vec![y * w]))
+            .collect();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            nodes = nodes
+                .chunks(2)
+                .zip(layer.chunks(2))
+                .map(|(values, factors)| match (values, factors) {
+                    ([left, right], [left_factor, right_factor]) => {
+                        &(left * right_factor) + &(right * left_factor)
+                    }
+                    ([single], [_]) => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        let mut polynomial = nodes.pop().unwrap();
+        polynomial.coefficients.resize(self.points.len(), F::zero());
+        polynomial
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::{UniformRand, Zero};
+
+    fn random_points(n: usize) -> Vec<Fr> {
+        let mut rng = ark_std::test_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_vanishing_polynomial() {
+        let points = random_points(13);
+        let tree = SubproductTree::new(&points);
+        let vanishing = tree.vanishing_polynomial();
+        assert_eq!(vanishing.coefficients.len(), 14);
+        assert!(points.iter().all(|&x| vanishing.evaluate(x).is_zero()));
+    }
+
+    #[test]
+    fn test_multipoint_evaluation() {
+        for n in [1, 2, 7, 37, 100] {
+            let points = random_points(n);
+            let polynomial = UnivariatePolynomial::new(random_points(150));
+            let tree = SubproductTree::new(&points);
+
+            let expected: Vec<Fr> = points.iter().map(|&x| polynomial.evaluate(x)).collect();
+            assert_eq!(tree.evaluate(&polynomial), expected);
+        }
+    }
+
+    #[test]
+    fn test_interpolation_round_trip() {
+        for n in [1, 5, 37, 80] {
+            let points = random_points(n);
+            let polynomial = UnivariatePolynomial::new(random_points(n + 1)[1..].to_vec());
+            let tree = SubproductTree::new(&points);
+
+            let values = tree.evaluate(&polynomial);
+            let interpolated = tree.interpolate(&values);
+            assert_eq!(interpolated.coefficients.len(), n);
+            assert_eq!(interpolated, polynomial);
+        }
+    }
+
+    #[test]
+    fn test_field_without_large_two_adic_subgroup() {
+        // BN254's base field falls back to schoolbook products throughout
+        let points: Vec<Fq> = (1..=70).map(Fq::from).collect();
+        let values: Vec<Fq> = (1..=70).map(|i| Fq::from(i * i)).collect();
+        let interpolated = SubproductTree::new(&points).interpolate(&values);
+        assert_eq!(interpolated.evaluate(Fq::from(10)), Fq::from(100));
+        assert_eq!(interpolated.degree(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation points must be distinct")]
+    fn test_repeated_points() {
+        let points = [Fr::from(1), Fr::from(2), Fr::from(1)];
+        SubproductTree::new(&points).interpolate(&[Fr::from(0); 3]);
+    }
+}